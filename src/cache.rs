@@ -0,0 +1,184 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tokio::sync::OnceCell;
+
+use crate::{client::Endpoint, errors::Error};
+
+/// Configures the in-memory response cache of a [`crate::Client`]
+///
+/// Responses are cached per endpoint and request parameters. Only endpoints with a TTL, either
+/// their own or the default one, are cached, and concurrent identical requests to a cached
+/// endpoint share a single HTTP call. Failed requests are never cached.
+///
+/// # Example
+///
+/// ```
+/// use septa_api::{cache::CacheConfig, Client, Endpoint};
+/// use std::time::Duration;
+///
+/// let client = Client::builder()
+///     .cache(
+///         CacheConfig::new()
+///             .with_endpoint_ttl(Endpoint::TrainView, Duration::from_secs(15))
+///             .with_endpoint_ttl(Endpoint::RailSchedule, Duration::from_secs(5 * 60)),
+///     )
+///     .build()
+///     .expect("client should build");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheConfig {
+    default_ttl: Option<Duration>,
+    endpoint_ttls: HashMap<Endpoint, Duration>,
+}
+
+impl CacheConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the TTL used by endpoints that do not have their own
+    pub fn with_default_ttl(mut self, ttl: Duration) -> Self {
+        self.default_ttl = Some(ttl);
+        self
+    }
+
+    /// Sets the TTL of a single endpoint
+    pub fn with_endpoint_ttl(mut self, endpoint: Endpoint, ttl: Duration) -> Self {
+        self.endpoint_ttls.insert(endpoint, ttl);
+        self
+    }
+
+    /// Returns the TTL of the endpoint, `None` if its responses are not cached
+    pub fn ttl(&self, endpoint: Endpoint) -> Option<Duration> {
+        self.endpoint_ttls
+            .get(&endpoint)
+            .copied()
+            .or(self.default_ttl)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    endpoint: Endpoint,
+    params: Vec<(&'static str, String)>,
+    response_type: TypeId,
+}
+
+struct CachedValue {
+    expires_at: Instant,
+    value: Arc<dyn Any + Send + Sync>,
+}
+
+type CacheEntry = Arc<OnceCell<CachedValue>>;
+
+pub(crate) struct ResponseCache {
+    config: CacheConfig,
+    entries: Mutex<HashMap<CacheKey, CacheEntry>>,
+}
+
+impl std::fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ResponseCache {
+    pub(crate) fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn ttl(&self, endpoint: Endpoint) -> Option<Duration> {
+        self.config.ttl(endpoint)
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clear();
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        Self::prune(&mut entries);
+        entries.len()
+    }
+
+    /// Removes expired entries and entries whose fetch failed or was cancelled
+    ///
+    /// An entry without a value is only kept while a task still holds it, that is while its
+    /// response is being fetched.
+    fn prune(entries: &mut HashMap<CacheKey, CacheEntry>) {
+        let now = Instant::now();
+
+        entries.retain(|_, entry| match entry.get() {
+            Some(cached_value) => cached_value.expires_at > now,
+            None => Arc::strong_count(entry) > 1,
+        });
+    }
+
+    /// Returns the cached response or calls `fetch` to populate the cache
+    ///
+    /// If another task is already fetching the same response this waits for it instead of
+    /// calling `fetch`. Should that fetch fail, the next waiting task makes its own attempt.
+    pub(crate) async fn get_or_fetch<R, F, Fut>(
+        &self,
+        endpoint: Endpoint,
+        params: Vec<(&'static str, String)>,
+        ttl: Duration,
+        fetch: F,
+    ) -> Result<R, Error>
+    where
+        R: Clone + Send + Sync + 'static,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<R, Error>>,
+    {
+        let key = CacheKey {
+            endpoint,
+            params,
+            response_type: TypeId::of::<R>(),
+        };
+
+        let entry = {
+            let mut entries = self
+                .entries
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            Self::prune(&mut entries);
+            entries.entry(key).or_default().clone()
+        };
+
+        let cached_value = entry
+            .get_or_try_init(|| async {
+                let value = fetch().await?;
+
+                Ok::<_, Error>(CachedValue {
+                    expires_at: Instant::now() + ttl,
+                    value: Arc::new(value),
+                })
+            })
+            .await?;
+
+        Ok(cached_value
+            .value
+            .downcast_ref::<R>()
+            .expect("the cache key includes the response type")
+            .clone())
+    }
+}
@@ -1,13 +1,66 @@
-use crate::{errors, requests, responses};
+use crate::{
+    cache::{CacheConfig, ResponseCache},
+    errors,
+    lenient::LenientClient,
+    requests, responses,
+    retry::RetryPolicy,
+    stream::{self, TrainViewEvent},
+    types::{BusRoute, LocationType},
+};
+use futures_util::Stream;
 use serde::de::DeserializeOwned;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use strum::{Display, EnumCount, EnumIter};
 
 const BASE_API_URL: &str = "https://www3.septa.org/api";
 
 pub type Result<T> = std::result::Result<T, errors::Error>;
 
+/// The SEPTA API endpoints that the [`Client`] can call
+#[derive(Clone, Copy, Debug, Display, EnumCount, EnumIter, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    Arrivals,
+    TrainView,
+    NextToArrive,
+    RailSchedule,
+    TransitView,
+    TransitViewAll,
+    AlertsSummary,
+    Alerts,
+    BusSchedules,
+    Locations,
+    ElevatorOutages,
+    BusDetours,
+    RouteStops,
+}
+
+impl Endpoint {
+    pub fn path(&self) -> &'static str {
+        match *self {
+            Self::Arrivals => "/Arrivals/index.php",
+            Self::TrainView => "/TrainView/index.php",
+            Self::NextToArrive => "/NextToArrive/index.php",
+            Self::RailSchedule => "/RRSchedules/index.php",
+            Self::TransitView => "/TransitView/index.php",
+            Self::TransitViewAll => "/TransitViewAll/index.php",
+            Self::AlertsSummary => "/Alerts/index.php",
+            Self::Alerts => "/Alerts/get_alert_data.php",
+            Self::BusSchedules => "/BusSchedules/index.php",
+            Self::Locations => "/locations/get_locations.php",
+            Self::ElevatorOutages => "/elevator/index.php",
+            Self::BusDetours => "/BusDetours/index.php",
+            Self::RouteStops => "/Stops/index.php",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Client {
     base_url: String,
+    http_client: reqwest::Client,
+    retry_policy: Option<RetryPolicy>,
+    endpoint_retry_policies: HashMap<Endpoint, RetryPolicy>,
+    cache: Option<Arc<ResponseCache>>,
 }
 
 impl Default for Client {
@@ -20,49 +73,195 @@ impl Client {
     pub fn new() -> Self {
         Self {
             base_url: BASE_API_URL.to_string(),
+            http_client: reqwest::Client::new(),
+            retry_policy: None,
+            endpoint_retry_policies: HashMap::new(),
+            cache: None,
         }
     }
 
     pub fn with_base_url(base_url: &str) -> Self {
         Self {
             base_url: base_url.to_string(),
+            http_client: reqwest::Client::new(),
+            retry_policy: None,
+            endpoint_retry_policies: HashMap::new(),
+            cache: None,
         }
     }
 
-    async fn get<R: DeserializeOwned>(&self, endpoint: &str) -> Result<R> {
-        let url = format!("{}{}", self.base_url, endpoint);
+    /// Returns a builder that can be used to configure the underlying HTTP client
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use septa_api::Client;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::builder()
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .read_timeout(Duration::from_secs(10))
+    ///     .user_agent("my-septa-app/1.0")
+    ///     .build()
+    ///     .expect("client should build");
+    /// ```
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
 
-        let response = reqwest::Client::new()
-            .get(url)
-            .send()
-            .await?
-            .json::<responses::ApiResponse<R>>()
-            .await?;
+    fn retry_policy(&self, endpoint: Endpoint) -> Option<&RetryPolicy> {
+        self.endpoint_retry_policies
+            .get(&endpoint)
+            .or(self.retry_policy.as_ref())
+    }
 
-        match response {
-            responses::ApiResponse::Error(error) => Err(errors::Error::ApiErrorResponse(error)),
-            responses::ApiResponse::Response(response) => Ok(response),
+    /// Returns a client that skips records that cannot be deserialized instead of failing
+    ///
+    /// The returned client shares the configuration, retry policies and cache of this one.
+    pub fn lenient(&self) -> LenientClient {
+        LenientClient::new(self.clone())
+    }
+
+    /// Removes every cached response, does nothing if the client has no cache
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
         }
     }
 
-    async fn get_request<T: requests::Request, R: DeserializeOwned>(
+    /// Returns the number of responses in the cache, including responses that are still being
+    /// fetched
+    ///
+    /// Expired responses and failed requests are not counted. Returns 0 if the client has no
+    /// cache.
+    pub fn cache_len(&self) -> usize {
+        self.cache.as_ref().map_or(0, |cache| cache.len())
+    }
+
+    pub(crate) async fn get<R: DeserializeOwned + Clone + Send + Sync + 'static>(
         &self,
-        endpoint: &str,
+        endpoint: Endpoint,
+    ) -> Result<R> {
+        self.get_with_params(endpoint, Vec::new()).await
+    }
+
+    pub(crate) async fn get_request<
+        T: requests::Request,
+        R: DeserializeOwned + Clone + Send + Sync + 'static,
+    >(
+        &self,
+        endpoint: Endpoint,
         request: T,
     ) -> Result<R> {
-        let url = format!("{}{}", self.base_url, endpoint);
-
-        let response = reqwest::Client::new()
-            .get(url)
-            .query(&request.into_params())
-            .send()
-            .await?
-            .json::<responses::ApiResponse<R>>()
-            .await?;
+        self.get_with_params(endpoint, request.into_params()).await
+    }
+
+    async fn get_with_params<R: DeserializeOwned + Clone + Send + Sync + 'static>(
+        &self,
+        endpoint: Endpoint,
+        params: Vec<(&'static str, String)>,
+    ) -> Result<R> {
+        match &self.cache {
+            Some(cache) => match cache.ttl(endpoint) {
+                Some(ttl) => {
+                    cache
+                        .get_or_fetch(endpoint, params.clone(), ttl, || {
+                            self.get_with_retries(endpoint, &params)
+                        })
+                        .await
+                }
+                None => self.get_with_retries(endpoint, &params).await,
+            },
+            None => self.get_with_retries(endpoint, &params).await,
+        }
+    }
+
+    async fn get_with_retries<R: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        params: &[(&'static str, String)],
+    ) -> Result<R> {
+        let mut attempt = 1;
+
+        loop {
+            let result = self.send(endpoint, params).await;
+
+            match (result, self.retry_policy(endpoint)) {
+                (Err(err), Some(policy))
+                    if err.is_retryable() && attempt < policy.max_attempts() =>
+                {
+                    tokio::time::sleep(policy.delay(attempt)).await;
+                    attempt += 1;
+                }
+                (result, _) => return result,
+            }
+        }
+    }
+
+    async fn send<R: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        params: &[(&'static str, String)],
+    ) -> Result<R> {
+        let url = format!("{}{}", self.base_url, endpoint.path());
+        let context = errors::ErrorContext::new(endpoint, params);
 
-        match response {
-            responses::ApiResponse::Error(error) => Err(errors::Error::ApiErrorResponse(error)),
-            responses::ApiResponse::Response(response) => Ok(response),
+        let response = match self.http_client.get(url).query(params).send().await {
+            Ok(response) => response,
+            Err(source) => {
+                return Err(errors::Error::RequestFailed {
+                    source,
+                    context: Some(Box::new(context)),
+                })
+            }
+        };
+
+        let context = context.with_status(response.status());
+        let status_result = response.error_for_status_ref().map(|_| ());
+
+        let bytes = match response.bytes().await {
+            Ok(bytes) => bytes,
+            Err(source) => {
+                return Err(errors::Error::RequestFailed {
+                    source,
+                    context: Some(Box::new(context)),
+                })
+            }
+        };
+
+        let context = context.with_body(&bytes);
+
+        if let Err(source) = status_result {
+            return Err(errors::Error::RequestFailed {
+                source,
+                context: Some(Box::new(context)),
+            });
+        }
+
+        match serde_json::from_slice::<responses::ApiResponse<R>>(&bytes) {
+            Ok(responses::ApiResponse::Error(message)) => Err(errors::Error::ApiErrorResponse {
+                message,
+                context: Some(Box::new(context)),
+            }),
+            Ok(responses::ApiResponse::Response(response)) => Ok(response),
+            Err(source) => {
+                // The untagged ApiResponse only reports that no variant matched, deserializing the
+                // expected response again points at the value that failed
+                let (source, context) = match serde_path_to_error::deserialize::<_, R>(
+                    &mut serde_json::Deserializer::from_slice(&bytes),
+                ) {
+                    Ok(_) => (source, context),
+                    Err(err) => {
+                        let json_path = err.path().to_string();
+                        (err.into_inner(), context.with_json_path(json_path))
+                    }
+                };
+
+                Err(errors::Error::DeserializeFailed {
+                    source,
+                    context: Some(Box::new(context)),
+                })
+            }
         }
     }
 
@@ -116,7 +315,7 @@ impl Client {
         &self,
         request: requests::ArrivalsRequest,
     ) -> Result<responses::ArrivalsResponse> {
-        self.get_request("/Arrivals/index.php", request).await
+        self.get_request(Endpoint::Arrivals, request).await
     }
 
     /// Returns a list of all active regional rail trains
@@ -140,7 +339,47 @@ impl Client {
     ///     Ok(())
     /// }
     pub async fn train_view(&self) -> Result<responses::TrainResponse> {
-        self.get("/TrainView/index.php").await
+        self.get(Endpoint::TrainView).await
+    }
+
+    /// Returns a stream of the changes between TrainView snapshots polled every `interval`
+    ///
+    /// This function repeatedly calls into the `/TrainView/index.php` endpoint, so any retry
+    /// policy or cache configured on the client applies to every poll.
+    ///
+    /// The first snapshot yields a [`TrainViewEvent::TrainAppeared`] for every active train and
+    /// later snapshots yield an event for every train that appeared, disappeared, moved, changed
+    /// lateness, next stop or track. Errors are yielded without ending the stream.
+    ///
+    /// Nothing is polled until the stream is, and an `interval` of zero is treated as one
+    /// millisecond.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use septa_api::{stream::TrainViewEvent, Client};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let mut events = Box::pin(client.train_view_stream(Duration::from_secs(30)));
+    ///
+    ///     while let Some(event) = events.next().await {
+    ///         if let TrainViewEvent::LatenessChanged { current, .. } = event? {
+    ///             println!("Train {} is now {} mins late", current.train_number, current.late);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn train_view_stream(
+        &self,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<TrainViewEvent>> {
+        stream::train_view_events(self.clone(), interval)
     }
 
     /// Returns departure and arrival times between two different stations
@@ -156,6 +395,7 @@ impl Client {
     /// ```
     /// use septa_api::Client;
     /// use septa_api::requests::NextToArriveRequest;
+    /// use septa_api::responses::NextToArrive;
     /// use septa_api::types::RegionalRailStop;
     ///
     /// #[tokio::main]
@@ -169,7 +409,12 @@ impl Client {
     ///     let response = client.next_to_arrive(request).await?;
     ///
     ///     for next_to_arrive in response {
-    ///        println!("Train {} is scheduled to arrive {}", next_to_arrive.orig_train, next_to_arrive.orig_departure_time);
+    ///        let leg = next_to_arrive.first_leg();
+    ///        println!("Train {} is scheduled to depart {}", leg.train, leg.departure_time);
+    ///
+    ///        if let NextToArrive::WithConnection { connection, second_leg, .. } = &next_to_arrive {
+    ///            println!("Connect at {} to train {}", connection, second_leg.train);
+    ///        }
     ///     }
     ///
     ///    Ok(())
@@ -178,7 +423,7 @@ impl Client {
         &self,
         request: requests::NextToArriveRequest,
     ) -> Result<responses::NextToArriveResponse> {
-        self.get_request("/NextToArrive/index.php", request).await
+        self.get_request(Endpoint::NextToArrive, request).await
     }
 
     /// Returns the schedule for a train by the train's number
@@ -213,6 +458,429 @@ impl Client {
         &self,
         request: requests::RailScheduleRequest,
     ) -> Result<responses::RailScheduleResponse> {
-        self.get_request("/RRSchedules/index.php", request).await
+        self.get_request(Endpoint::RailSchedule, request).await
+    }
+
+    /// Returns the real-time positions of all the vehicles on a bus or trolley route
+    ///
+    /// This function calls into the `/TransitView/index.php` endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A struct containing the request parameters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use septa_api::Client;
+    /// use septa_api::requests::TransitViewRequest;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let request = TransitViewRequest {
+    ///         route: "17".parse()?
+    ///     };
+    ///     let response = client.transit_view(request).await?;
+    ///
+    ///     for vehicle in response.vehicles {
+    ///        println!("Vehicle {} is heading to {:?} and is {} mins late", vehicle.vehicle_id, vehicle.destination, vehicle.late);
+    ///     }
+    ///
+    ///    Ok(())
+    /// }
+    pub async fn transit_view(
+        &self,
+        request: requests::TransitViewRequest,
+    ) -> Result<responses::TransitViewResponse> {
+        self.get_request(Endpoint::TransitView, request).await
+    }
+
+    /// Returns the real-time positions of all the vehicles on every bus and trolley route
+    ///
+    /// This function calls into the `/TransitViewAll/index.php` endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use septa_api::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let response = client.transit_view_all().await?;
+    ///
+    ///     for (route, vehicles) in response.routes {
+    ///        println!("Route {} has {} active vehicles", route, vehicles.len());
+    ///     }
+    ///
+    ///    Ok(())
+    /// }
+    pub async fn transit_view_all(&self) -> Result<responses::TransitViewAllResponse> {
+        self.get(Endpoint::TransitViewAll).await
+    }
+
+    /// Returns a summary of the alerts, advisories and detours for every route
+    ///
+    /// This function calls into the `/Alerts/index.php` endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use septa_api::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let response = client.alerts_summary().await?;
+    ///
+    ///     for summary in response.iter().filter(|summary| summary.is_alert) {
+    ///        println!("Route {} has an active alert", summary.route_name);
+    ///     }
+    ///
+    ///    Ok(())
+    /// }
+    pub async fn alerts_summary(&self) -> Result<responses::AlertsSummaryResponse> {
+        self.get(Endpoint::AlertsSummary).await
+    }
+
+    /// Returns the alert messages, advisories and detours for a route or for every route
+    ///
+    /// This function calls into the `/Alerts/get_alert_data.php` endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A struct containing the request parameters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use septa_api::Client;
+    /// use septa_api::requests::AlertsRequest;
+    /// use septa_api::types::{AlertRoute, RegionalRailsLine};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let request = AlertsRequest {
+    ///         route: Some(AlertRoute::RegionalRail(RegionalRailsLine::PaoliThorndale)),
+    ///     };
+    ///     let response = client.alerts(request).await?;
+    ///
+    ///     for alert in response {
+    ///        println!("{}: {:?}", alert.route_name, alert.advisory_message);
+    ///     }
+    ///
+    ///    Ok(())
+    /// }
+    pub async fn alerts(
+        &self,
+        request: requests::AlertsRequest,
+    ) -> Result<responses::AlertsResponse> {
+        self.get_request(Endpoint::Alerts, request).await
+    }
+
+    /// Returns the upcoming scheduled departures of the buses and trolleys at a stop
+    ///
+    /// This function calls into the `/BusSchedules/index.php` endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A struct containing the request parameters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use septa_api::Client;
+    /// use septa_api::requests::{BusDirection, BusSchedulesRequest};
+    /// use septa_api::types::BusRoute;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let request = BusSchedulesRequest {
+    ///         stop_id: 17842,
+    ///         route: Some("17".parse::<BusRoute>()?),
+    ///         direction: Some(BusDirection::Inbound),
+    ///         results: Some(5),
+    ///     };
+    ///     let response = client.bus_schedules(request).await?;
+    ///
+    ///     for (route, departures) in response {
+    ///         for departure in departures {
+    ///             println!("Route {} departs {} at {}", route, departure.stop_name, departure.scheduled_time);
+    ///         }
+    ///     }
+    ///
+    ///    Ok(())
+    /// }
+    pub async fn bus_schedules(
+        &self,
+        request: requests::BusSchedulesRequest,
+    ) -> Result<responses::BusSchedulesResponse> {
+        self.get_request(Endpoint::BusSchedules, request).await
+    }
+
+    /// Returns the locations of a type within a radius of a point, closest first
+    ///
+    /// This function calls into the `/locations/get_locations.php` endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `lat` - The latitude of the point to search around
+    /// * `lon` - The longitude of the point to search around
+    /// * `radius` - The search radius in miles
+    /// * `location_type` - The type of locations to search for
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use septa_api::Client;
+    /// use septa_api::types::LocationType;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let response = client
+    ///         .nearby_locations(39.9526, -75.1652, 0.5, LocationType::RailStations)
+    ///         .await?;
+    ///
+    ///     for location in response {
+    ///         if let Some(stop) = location.rail_stop() {
+    ///             println!("{} is {} miles away", stop, location.distance);
+    ///         }
+    ///     }
+    ///
+    ///    Ok(())
+    /// }
+    pub async fn nearby_locations(
+        &self,
+        lat: f64,
+        lon: f64,
+        radius: f64,
+        location_type: LocationType,
+    ) -> Result<responses::LocationsResponse> {
+        let request = requests::LocationsRequest {
+            lat,
+            lon,
+            radius,
+            location_type,
+        };
+
+        self.get_request(Endpoint::Locations, request).await
+    }
+
+    /// Returns the elevators that are currently out of service
+    ///
+    /// This function calls into the `/elevator/index.php` endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use septa_api::Client;
+    /// use septa_api::types::RegionalRailStop;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let response = client.elevator_outages().await?;
+    ///
+    ///     for outage in response.outages.iter() {
+    ///         println!("{} at {}: {}", outage.elevator, outage.station, outage.message);
+    ///     }
+    ///
+    ///     if response.has_outage_at(&RegionalRailStop::SuburbanStation) {
+    ///         println!("Suburban Station has an elevator outage");
+    ///     }
+    ///
+    ///    Ok(())
+    /// }
+    pub async fn elevator_outages(&self) -> Result<responses::ElevatorOutagesResponse> {
+        self.get(Endpoint::ElevatorOutages).await
+    }
+
+    /// Returns the active detours of a bus or trolley route or of every route
+    ///
+    /// This function calls into the `/BusDetours/index.php` endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `route` - The route to return the detours of, every route with a detour is returned when
+    ///   `None`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use septa_api::Client;
+    /// use septa_api::types::BusRoute;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let response = client.bus_detours(Some("17".parse::<BusRoute>()?)).await?;
+    ///
+    ///     for route in response {
+    ///         for detour in route.detours {
+    ///             println!("Route {} is detoured at {:?} because of {:?}", route.route_id, detour.start_location, detour.reason);
+    ///         }
+    ///     }
+    ///
+    ///    Ok(())
+    /// }
+    pub async fn bus_detours(
+        &self,
+        route: Option<BusRoute>,
+    ) -> Result<responses::BusDetoursResponse> {
+        self.get_request(Endpoint::BusDetours, requests::BusDetoursRequest { route })
+            .await
+    }
+
+    /// Returns the stops of a bus or trolley route in the order they are served
+    ///
+    /// This function calls into the `/Stops/index.php` endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `route` - The route to return the stops of
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use septa_api::Client;
+    /// use septa_api::types::BusRoute;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let response = client.route_stops("17".parse::<BusRoute>()?).await?;
+    ///
+    ///     for stop in response {
+    ///         println!("{} ({})", stop.name, stop.stop_id);
+    ///     }
+    ///
+    ///    Ok(())
+    /// }
+    pub async fn route_stops(&self, route: BusRoute) -> Result<responses::RouteStopsResponse> {
+        let mut stops: responses::RouteStopsResponse = self
+            .get_request(Endpoint::RouteStops, requests::RouteStopsRequest { route })
+            .await?;
+
+        // SEPTA lists the stops in order without numbering them
+        for (index, stop) in stops.iter_mut().enumerate() {
+            stop.sequence = Some(index as u32 + 1);
+        }
+
+        Ok(stops)
+    }
+}
+
+/// A builder for a [`Client`] that holds a single, reusable HTTP client
+///
+/// Every request made by the built [`Client`] shares the same connection pool, so the builder
+/// should be used once and the resulting [`Client`] cloned wherever it is needed.
+#[derive(Debug, Default)]
+pub struct ClientBuilder {
+    base_url: Option<String>,
+    http_client_builder: reqwest::ClientBuilder,
+    retry_policy: Option<RetryPolicy>,
+    endpoint_retry_policies: HashMap<Endpoint, RetryPolicy>,
+    cache_config: Option<CacheConfig>,
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the base url of the SEPTA API, defaults to `https://www3.septa.org/api`
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.to_string());
+        self
+    }
+
+    /// Sets a timeout for the entire request, from connecting until the response body is read
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_client_builder = self.http_client_builder.timeout(timeout);
+        self
+    }
+
+    /// Sets a timeout for establishing the connection to the server
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_client_builder = self.http_client_builder.connect_timeout(timeout);
+        self
+    }
+
+    /// Sets a timeout for each read from the server, resetting after every successful read
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.http_client_builder = self.http_client_builder.read_timeout(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.http_client_builder = self.http_client_builder.user_agent(user_agent);
+        self
+    }
+
+    /// Sets headers that are sent with every request
+    pub fn default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.http_client_builder = self.http_client_builder.default_headers(headers);
+        self
+    }
+
+    /// Adds a proxy that requests are routed through
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.http_client_builder = self.http_client_builder.proxy(proxy);
+        self
+    }
+
+    /// Sets how long an idle connection is kept in the pool, `None` keeps it forever
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.http_client_builder = self.http_client_builder.pool_idle_timeout(timeout);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept in the pool per host
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.http_client_builder = self.http_client_builder.pool_max_idle_per_host(max);
+        self
+    }
+
+    /// Retries requests that fail with a transient error according to `retry_policy`
+    ///
+    /// Requests are not retried unless a policy is set.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Overrides the retry policy for a single endpoint
+    pub fn endpoint_retry_policy(mut self, endpoint: Endpoint, retry_policy: RetryPolicy) -> Self {
+        self.endpoint_retry_policies.insert(endpoint, retry_policy);
+        self
+    }
+
+    /// Caches responses in memory according to `cache_config`
+    ///
+    /// The cache is shared between the built [`Client`] and all of its clones.
+    pub fn cache(mut self, cache_config: CacheConfig) -> Self {
+        self.cache_config = Some(cache_config);
+        self
+    }
+
+    /// Builds the [`Client`]
+    ///
+    /// Fails with [`errors::Error::RequestFailed`] if the underlying HTTP client could not be
+    /// created, for example if the TLS backend could not be initialized.
+    pub fn build(self) -> Result<Client> {
+        Ok(Client {
+            base_url: self.base_url.unwrap_or_else(|| BASE_API_URL.to_string()),
+            http_client: self.http_client_builder.build()?,
+            retry_policy: self.retry_policy,
+            endpoint_retry_policies: self.endpoint_retry_policies,
+            cache: self
+                .cache_config
+                .map(|cache_config| Arc::new(ResponseCache::new(cache_config))),
+        })
     }
 }
@@ -2,6 +2,8 @@ use chrono::{NaiveDateTime, NaiveTime};
 use serde::de;
 use std::{collections::HashMap, fmt, str::FromStr};
 
+use crate::types::{Track, TrainStatus};
+
 struct CsvEncodedStringVisitor;
 
 impl<'a> de::Visitor<'a> for CsvEncodedStringVisitor {
@@ -185,6 +187,81 @@ impl<'a> de::Visitor<'a> for NaiveDateTimeVisitor {
     }
 }
 
+pub fn deserialize_naive_date_time_with_slashes<'a, D: de::Deserializer<'a>>(
+    deserializer: D,
+) -> Result<NaiveDateTime, D::Error> {
+    deserializer.deserialize_str(NaiveDateTimeWithSlashesVisitor)
+}
+
+// SEPTA uses both two and four digit years, a four digit year fails the first format since it
+// leaves the last two digits unparsed
+fn parse_naive_date_time_with_slashes<E: de::Error>(value: &str) -> Result<NaiveDateTime, E> {
+    const DATE_TIME_FORMAT: &str = "%m/%d/%y %I:%M %p";
+    const DATE_TIME_FORMAT_WITH_FULL_YEAR: &str = "%m/%d/%Y %I:%M %p";
+
+    NaiveDateTime::parse_from_str(value, DATE_TIME_FORMAT)
+        .or_else(|err| {
+            NaiveDateTime::parse_from_str(value, DATE_TIME_FORMAT_WITH_FULL_YEAR).map_err(|_| err)
+        })
+        .map_err(|err| E::custom(format!("Error {} parsing timestamp {}", err, value)))
+}
+
+struct NaiveDateTimeWithSlashesVisitor;
+
+impl<'a> de::Visitor<'a> for NaiveDateTimeWithSlashesVisitor {
+    type Value = NaiveDateTime;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a date string with slashes such as '04/25/23 04:21 pm' or '6/13/2023 9:00 AM'"
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_naive_date_time_with_slashes(value)
+    }
+}
+
+pub fn deserialize_option_naive_date_time_with_slashes<'a, D: de::Deserializer<'a>>(
+    deserializer: D,
+) -> Result<Option<NaiveDateTime>, D::Error> {
+    deserializer.deserialize_option(OptionNaiveDateTimeWithSlashesVisitor)
+}
+
+struct OptionNaiveDateTimeWithSlashesVisitor;
+
+impl<'a> de::Visitor<'a> for OptionNaiveDateTimeWithSlashesVisitor {
+    type Value = Option<NaiveDateTime>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a date string with slashes such as '6/13/2023 9:00 AM' or an empty string"
+        )
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: de::Deserializer<'a>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_str(self)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        if value.trim().is_empty() {
+            Ok(None)
+        } else {
+            parse_naive_date_time_with_slashes(value).map(Some)
+        }
+    }
+}
+
 pub fn deserialize_naive_time<'a, D: de::Deserializer<'a>>(
     deserializer: D,
 ) -> Result<NaiveTime, D::Error> {
@@ -208,6 +285,45 @@ impl<'a> de::Visitor<'a> for NaiveTimeVisitor {
     }
 }
 
+pub fn deserialize_optional_naive_time<'a, D: de::Deserializer<'a>>(
+    deserializer: D,
+) -> Result<Option<NaiveTime>, D::Error> {
+    deserializer.deserialize_option(OptionNaiveTimeVisitor)
+}
+
+struct OptionNaiveTimeVisitor;
+
+impl<'a> de::Visitor<'a> for OptionNaiveTimeVisitor {
+    type Value = Option<NaiveTime>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a trivially encoded time string that can be empty or null"
+        )
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: de::Deserializer<'a>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_str(self)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        if value.trim().is_empty() {
+            Ok(None)
+        } else {
+            NaiveTimeVisitor.visit_str(value).map(Some)
+        }
+    }
+}
+
 pub fn deserialize_naive_time_with_space<'a, D: de::Deserializer<'a>>(
     deserializer: D,
 ) -> Result<NaiveTime, D::Error> {
@@ -286,7 +402,7 @@ impl<'a> de::Visitor<'a> for BoolStringVisitor {
 }
 
 pub fn deserialize_f64<'a, D: de::Deserializer<'a>>(deserializer: D) -> Result<f64, D::Error> {
-    deserializer.deserialize_str(F64StringVisitor)
+    deserializer.deserialize_any(F64StringVisitor)
 }
 
 struct F64StringVisitor;
@@ -295,7 +411,19 @@ impl<'a> de::Visitor<'a> for F64StringVisitor {
     type Value = f64;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "a string encoded f64")
+        write!(formatter, "an f64 or a string encoded f64")
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(value as f64)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(value as f64)
     }
 
     fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
@@ -317,7 +445,7 @@ impl<'a> de::Visitor<'a> for OptionF64StringVisitor {
     type Value = Option<f64>;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "a string encoded f64 or null")
+        write!(formatter, "an f64, a string encoded f64 or null")
     }
 
     fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
@@ -329,8 +457,193 @@ impl<'a> de::Visitor<'a> for OptionF64StringVisitor {
     }
 
     fn visit_some<D: de::Deserializer<'a>>(self, d: D) -> Result<Self::Value, D::Error> {
-        Ok(d.deserialize_str(F64StringVisitor)
+        // TransitView sends headings as numbers while TrainView sends them as strings
+        Ok(d.deserialize_any(F64StringVisitor)
             .map(Some)
             .unwrap_or(None))
     }
 }
+
+pub fn deserialize_i32<'a, D: de::Deserializer<'a>>(deserializer: D) -> Result<i32, D::Error> {
+    deserializer.deserialize_any(I32StringVisitor)
+}
+
+struct I32StringVisitor;
+
+impl<'a> de::Visitor<'a> for I32StringVisitor {
+    type Value = i32;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an i32 or a string encoded i32")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        i32::try_from(value)
+            .map_err(|e| de::Error::custom(format!("Error {} converting i32 {}", e, value)))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        i32::try_from(value)
+            .map_err(|e| de::Error::custom(format!("Error {} converting i32 {}", e, value)))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value
+            .parse::<i32>()
+            .map_err(|e| de::Error::custom(format!("Error {} parsing i32 {}", e, value)))
+    }
+}
+
+pub fn deserialize_u32<'a, D: de::Deserializer<'a>>(deserializer: D) -> Result<u32, D::Error> {
+    deserializer.deserialize_any(U32StringVisitor)
+}
+
+struct U32StringVisitor;
+
+impl<'a> de::Visitor<'a> for U32StringVisitor {
+    type Value = u32;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a u32 or a string encoded u32")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u32::try_from(value)
+            .map_err(|e| de::Error::custom(format!("Error {} converting u32 {}", e, value)))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        u32::try_from(value)
+            .map_err(|e| de::Error::custom(format!("Error {} converting u32 {}", e, value)))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value
+            .parse::<u32>()
+            .map_err(|e| de::Error::custom(format!("Error {} parsing u32 {}", e, value)))
+    }
+}
+
+pub fn deserialize_yes_no_bool<'a, D: de::Deserializer<'a>>(
+    deserializer: D,
+) -> Result<bool, D::Error> {
+    deserializer.deserialize_str(YesNoBoolStringVisitor)
+}
+
+struct YesNoBoolStringVisitor;
+
+impl<'a> de::Visitor<'a> for YesNoBoolStringVisitor {
+    type Value = bool;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a yes/no encoded bool")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        match value.to_ascii_lowercase().as_str() {
+            "yes" | "y" => Ok(true),
+            "no" | "n" => Ok(false),
+            _ => Err(de::Error::unknown_variant(value, &["Yes", "No", "Y", "N"])),
+        }
+    }
+}
+
+pub fn deserialize_optional_string<'a, D: de::Deserializer<'a>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    deserializer.deserialize_option(OptionStringVisitor)
+}
+
+struct OptionStringVisitor;
+
+impl<'a> de::Visitor<'a> for OptionStringVisitor {
+    type Value = Option<String>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a string that can be empty or null")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: de::Deserializer<'a>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_str(self)
+    }
+
+    // SEPTA uses an empty string to represent a missing value
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        if value.trim().is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(value.to_string()))
+        }
+    }
+}
+
+/// Deserializes a track that SEPTA sends as either an empty string or null when there is none
+pub fn deserialize_optional_track<'a, D: de::Deserializer<'a>>(
+    deserializer: D,
+) -> Result<Option<Track>, D::Error> {
+    deserialize_optional_string(deserializer)?
+        .map(|track| track.parse::<Track>().map_err(de::Error::custom))
+        .transpose()
+}
+
+pub fn deserialize_option_naive_date_time_with_month<'a, D: de::Deserializer<'a>>(
+    deserializer: D,
+) -> Result<Option<NaiveDateTime>, D::Error> {
+    deserializer.deserialize_str(OptionNaiveDateTimeWithMonthVisitor)
+}
+
+struct OptionNaiveDateTimeWithMonthVisitor;
+
+impl<'a> de::Visitor<'a> for OptionNaiveDateTimeWithMonthVisitor {
+    type Value = Option<NaiveDateTime>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a date string with an abbreviated month name such as 'Jun 13 2023 10:23:12:000AM' or an empty string"
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        const DATE_TIME_FORMAT: &str = "%b %d %Y %I:%M:%S:%3f%p";
+
+        if value.trim().is_empty() {
+            Ok(None)
+        } else {
+            NaiveDateTime::parse_from_str(value, DATE_TIME_FORMAT)
+                .map(Some)
+                .map_err(|err| E::custom(format!("Error {} parsing timestamp {}", err, value)))
+        }
+    }
+}
+
+pub fn deserialize_train_status<'a, D: de::Deserializer<'a>>(
+    deserializer: D,
+) -> Result<TrainStatus, D::Error> {
+    deserializer.deserialize_str(TrainStatusVisitor)
+}
+
+struct TrainStatusVisitor;
+
+impl<'a> de::Visitor<'a> for TrainStatusVisitor {
+    type Value = TrainStatus;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a train status string such as 'On Time', '12 min' or 'Suspended'"
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(TrainStatus::from(value))
+    }
+}
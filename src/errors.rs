@@ -1,14 +1,179 @@
+use std::fmt;
+
+use reqwest::StatusCode;
+
+use crate::client::Endpoint;
+
+/// The maximum number of bytes of the response body kept in an [`ErrorContext`]
+pub const MAX_ERROR_BODY_LEN: usize = 1024;
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("Unable to send the request: {0}")]
-    RequestFailed(#[from] reqwest::Error),
+    #[error("Unable to send the request: {source}{}", display_context(.context))]
+    RequestFailed {
+        source: reqwest::Error,
+        context: Option<Box<ErrorContext>>,
+    },
 
-    #[error("Unable to deserialize the received value: {0}")]
-    DeserializeFailed(#[from] serde_json::error::Error),
+    #[error("Unable to deserialize the received value: {source}{}", display_context(.context))]
+    DeserializeFailed {
+        source: serde_json::error::Error,
+        context: Option<Box<ErrorContext>>,
+    },
 
-    #[error("API returned an error response: {0}")]
-    ApiErrorResponse(String),
+    #[error("API returned an error response: {message}{}", display_context(.context))]
+    ApiErrorResponse {
+        message: String,
+        context: Option<Box<ErrorContext>>,
+    },
 
     #[error("Unknown regional rail station: {0}")]
     UnknownRegionalRailStation(String),
+
+    #[error("Invalid route: {0}")]
+    InvalidRoute(String),
+
+    #[error("Invalid track: {0}")]
+    InvalidTrack(String),
+}
+
+/// Describes the request and response that caused an [`Error`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    pub endpoint: Endpoint,
+    pub params: Vec<(&'static str, String)>,
+
+    /// The HTTP status of the response, `None` if no response was received
+    pub status: Option<StatusCode>,
+
+    /// The start of the response body, at most [`MAX_ERROR_BODY_LEN`] bytes
+    pub body: Option<String>,
+
+    /// The path to the value that could not be deserialized, for example `[0].lat`
+    pub json_path: Option<String>,
+}
+
+impl ErrorContext {
+    pub(crate) fn new(endpoint: Endpoint, params: &[(&'static str, String)]) -> Self {
+        Self {
+            endpoint,
+            params: params.to_vec(),
+            status: None,
+            body: None,
+            json_path: None,
+        }
+    }
+
+    pub(crate) fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub(crate) fn with_body(mut self, body: &[u8]) -> Self {
+        // A multi-byte character cut in half by the truncation is replaced by U+FFFD
+        self.body =
+            Some(String::from_utf8_lossy(&body[..body.len().min(MAX_ERROR_BODY_LEN)]).into_owned());
+        self
+    }
+
+    pub(crate) fn with_json_path(mut self, json_path: String) -> Self {
+        self.json_path = Some(json_path);
+        self
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "endpoint: {}", self.endpoint)?;
+
+        if !self.params.is_empty() {
+            let params = self
+                .params
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect::<Vec<_>>();
+            write!(f, ", params: {}", params.join("&"))?;
+        }
+
+        if let Some(status) = self.status {
+            write!(f, ", status: {}", status)?;
+        }
+
+        if let Some(json_path) = &self.json_path {
+            write!(f, ", path: {}", json_path)?;
+        }
+
+        if let Some(body) = &self.body {
+            write!(f, ", body: {:?}", body)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn display_context(context: &Option<Box<ErrorContext>>) -> String {
+    match context {
+        Some(context) => format!(" ({})", context),
+        None => String::new(),
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(source: reqwest::Error) -> Self {
+        Self::RequestFailed {
+            source,
+            context: None,
+        }
+    }
+}
+
+impl From<serde_json::error::Error> for Error {
+    fn from(source: serde_json::error::Error) -> Self {
+        Self::DeserializeFailed {
+            source,
+            context: None,
+        }
+    }
+}
+
+impl Error {
+    /// Returns the context of the request that failed, `None` if the error did not come from a
+    /// request made by the [`crate::Client`]
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::RequestFailed { context, .. }
+            | Self::DeserializeFailed { context, .. }
+            | Self::ApiErrorResponse { context, .. } => context.as_deref(),
+            Self::UnknownRegionalRailStation(_) | Self::InvalidRoute(_) | Self::InvalidTrack(_) => {
+                None
+            }
+        }
+    }
+
+    /// Returns true if the error is likely to be transient and the request worth retrying
+    ///
+    /// Network failures, timeouts, 5xx responses and bodies that are not valid JSON (SEPTA will
+    /// sometimes send an HTML error page) are retryable. 4xx responses, error messages returned by
+    /// the API and JSON that does not match the expected response are not.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::RequestFailed { source, .. } => match source.status() {
+                Some(status) => status.is_server_error(),
+                None => {
+                    source.is_timeout()
+                        || source.is_connect()
+                        || source.is_request()
+                        || source.is_body()
+                }
+            },
+            Self::DeserializeFailed { source, .. } => matches!(
+                source.classify(),
+                serde_json::error::Category::Syntax | serde_json::error::Category::Eof
+            ),
+            Self::ApiErrorResponse { .. }
+            | Self::UnknownRegionalRailStation(_)
+            | Self::InvalidRoute(_)
+            | Self::InvalidTrack(_) => false,
+        }
+    }
 }
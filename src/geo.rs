@@ -0,0 +1,123 @@
+use strum::IntoEnumIterator;
+
+use crate::types::{KnownRegionalRailStop, RegionalRailStop};
+
+/// The mean radius of the earth in meters
+const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// Returns the great-circle distance in meters between two `(lat, lon)` points
+pub fn haversine_distance(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lon1) = (from.0.to_radians(), from.1.to_radians());
+    let (lat2, lon2) = (to.0.to_radians(), to.1.to_radians());
+
+    let a = ((lat2 - lat1) / 2.0).sin().powi(2)
+        + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_METERS * a.sqrt().min(1.0).asin()
+}
+
+/// Returns the initial bearing in degrees, clockwise from north in `[0, 360)`, to travel from one
+/// `(lat, lon)` point to another along a great circle
+pub fn bearing(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lon1) = (from.0.to_radians(), from.1.to_radians());
+    let (lat2, lon2) = (to.0.to_radians(), to.1.to_radians());
+    let delta_lon = lon2 - lon1;
+
+    let y = delta_lon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+
+    y.atan2(x).to_degrees().rem_euclid(360.0)
+}
+
+/// A regional rail stop found by a [`RegionalRailStopIndex`] query
+#[derive(Clone, Debug, PartialEq)]
+pub struct NearbyStop {
+    pub stop: RegionalRailStop,
+
+    /// The distance in meters from the queried point to the stop
+    pub distance: f64,
+
+    /// The bearing in degrees, clockwise from north, from the queried point to the stop
+    pub bearing: f64,
+}
+
+/// An offline index over the coordinates of every known [`RegionalRailStop`]
+///
+/// The index answers nearest neighbor and radius queries without calling the API.
+///
+/// # Example
+///
+/// ```
+/// use septa_api::geo::RegionalRailStopIndex;
+/// use septa_api::types::RegionalRailStop;
+///
+/// let index = RegionalRailStopIndex::new();
+///
+/// // Philadelphia City Hall
+/// let nearest = index.nearest(39.952335, -75.163789, 1);
+/// assert_eq!(nearest[0].stop, RegionalRailStop::SuburbanStation);
+///
+/// for nearby in index.within(39.952335, -75.163789, 1000.0) {
+///     println!("{} is {:.0}m away at {:.0}°", nearby.stop, nearby.distance, nearby.bearing);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RegionalRailStopIndex {
+    stops: Vec<(RegionalRailStop, (f64, f64))>,
+}
+
+impl Default for RegionalRailStopIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegionalRailStopIndex {
+    pub fn new() -> Self {
+        let stops = KnownRegionalRailStop::iter()
+            .map(|stop| {
+                let lat_lon = stop.lat_lon();
+                (stop.into(), lat_lon)
+            })
+            .collect();
+
+        Self { stops }
+    }
+
+    /// Returns the `k` stops closest to the point, closest first
+    pub fn nearest(&self, lat: f64, lon: f64, k: usize) -> Vec<NearbyStop> {
+        let mut stops = self.query(lat, lon).collect::<Vec<NearbyStop>>();
+        Self::sort_by_distance(&mut stops);
+        stops.truncate(k);
+        stops
+    }
+
+    /// Returns every stop within `meters` of the point, closest first
+    pub fn within(&self, lat: f64, lon: f64, meters: f64) -> Vec<NearbyStop> {
+        let mut stops = self
+            .query(lat, lon)
+            .filter(|nearby| nearby.distance <= meters)
+            .collect::<Vec<NearbyStop>>();
+        Self::sort_by_distance(&mut stops);
+        stops
+    }
+
+    fn query(&self, lat: f64, lon: f64) -> impl Iterator<Item = NearbyStop> + '_ {
+        self.stops
+            .iter()
+            .map(move |(stop, stop_lat_lon)| NearbyStop {
+                stop: stop.clone(),
+                distance: haversine_distance((lat, lon), *stop_lat_lon),
+                bearing: bearing((lat, lon), *stop_lat_lon),
+            })
+    }
+
+    // Ties are broken by the stop so the results are deterministic
+    fn sort_by_distance(stops: &mut [NearbyStop]) {
+        stops.sort_by(|a, b| {
+            a.distance
+                .total_cmp(&b.distance)
+                .then_with(|| a.stop.cmp(&b.stop))
+        });
+    }
+}
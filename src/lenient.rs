@@ -0,0 +1,188 @@
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+
+use crate::{
+    client::{Client, Endpoint, Result},
+    requests,
+    requests::Direction,
+    responses::{self, RawArrivalsResponse},
+};
+
+/// A response where the records that could not be deserialized were skipped
+#[derive(Debug, Clone)]
+pub struct LenientResponse<T> {
+    /// The records that were deserialized
+    pub records: T,
+
+    /// The records that were skipped, in the order they appeared in the response
+    pub errors: Vec<RecordError>,
+}
+
+/// A record of a response that could not be deserialized
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordError {
+    /// The position of the record in the list of records it was received in
+    pub index: usize,
+
+    /// The list of an arrivals response the record was received in, `None` for endpoints that
+    /// respond with a single list
+    pub direction: Option<Direction>,
+
+    /// The record as it was received
+    pub raw: serde_json::Value,
+
+    /// Why the record could not be deserialized, including the path to the offending field
+    pub reason: String,
+}
+
+/// Calls the same endpoints as [`Client`], but skips records that cannot be deserialized
+///
+/// A single record with an unexpected value, for example a station that
+/// [`crate::types::RegionalRailStop`] does not know about, fails the whole response when using
+/// [`Client`]. The lenient client instead returns every record that could be deserialized
+/// together with a [`RecordError`] for each one that could not. Errors that affect the response
+/// as a whole, like a failed request or an error returned by the API, are still returned as
+/// [`crate::errors::Error`].
+///
+/// # Example
+///
+/// ```
+/// use septa_api::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///     let response = client.lenient().train_view().await?;
+///
+///     for train in response.records {
+///         println!("Train {} is currently {} mins late", train.train_number, train.late);
+///     }
+///
+///     for error in response.errors {
+///         println!("Skipped train at index {}: {}", error.index, error.reason);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LenientClient {
+    client: Client,
+}
+
+impl LenientClient {
+    pub(crate) fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Leniently calls the `/Arrivals/index.php` endpoint, see [`Client::arrivals`]
+    ///
+    /// The index of a [`RecordError`] is its position in the list given by its direction, errors
+    /// for the northbound list come first.
+    pub async fn arrivals(
+        &self,
+        request: requests::ArrivalsRequest,
+    ) -> Result<LenientResponse<responses::ArrivalsResponse>> {
+        let raw: RawArrivalsResponse = self.client.get_request(Endpoint::Arrivals, request).await?;
+
+        let (northbound, mut errors) = deserialize_records(raw.northbound, Some(Direction::North));
+        let (southbound, southbound_errors) =
+            deserialize_records(raw.southbound, Some(Direction::South));
+        errors.extend(southbound_errors);
+
+        Ok(LenientResponse {
+            records: responses::ArrivalsResponse {
+                title: raw.title,
+                northbound,
+                southbound,
+            },
+            errors,
+        })
+    }
+
+    /// Leniently calls the `/TrainView/index.php` endpoint, see [`Client::train_view`]
+    pub async fn train_view(&self) -> Result<LenientResponse<responses::TrainResponse>> {
+        let raw: RawRecords = self.client.get(Endpoint::TrainView).await?;
+        Ok(raw.into_response())
+    }
+
+    /// Leniently calls the `/NextToArrive/index.php` endpoint, see [`Client::next_to_arrive`]
+    pub async fn next_to_arrive(
+        &self,
+        request: requests::NextToArriveRequest,
+    ) -> Result<LenientResponse<responses::NextToArriveResponse>> {
+        let raw: RawRecords = self
+            .client
+            .get_request(Endpoint::NextToArrive, request)
+            .await?;
+        Ok(raw.into_response())
+    }
+
+    /// Leniently calls the `/RRSchedules/index.php` endpoint, see [`Client::rail_schedule`]
+    pub async fn rail_schedule(
+        &self,
+        request: requests::RailScheduleRequest,
+    ) -> Result<LenientResponse<responses::RailScheduleResponse>> {
+        let raw: RawRecords = self
+            .client
+            .get_request(Endpoint::RailSchedule, request)
+            .await?;
+        Ok(raw.into_response())
+    }
+}
+
+/// A list of records left as raw JSON
+///
+/// The API reports errors as a list with a single `error` object, which would otherwise be a
+/// valid list of records. Rejecting it here lets [`responses::ApiResponse`] fall back to its
+/// error variant.
+#[derive(Debug, Clone)]
+struct RawRecords(Vec<serde_json::Value>);
+
+impl<'de> Deserialize<'de> for RawRecords {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let values = Vec::<serde_json::Value>::deserialize(deserializer)?;
+
+        let is_api_error = values.iter().any(|value| {
+            value
+                .as_object()
+                .is_some_and(|object| object.contains_key("error"))
+        });
+
+        if is_api_error {
+            return Err(serde::de::Error::custom(
+                "expected records, found an API error",
+            ));
+        }
+
+        Ok(RawRecords(values))
+    }
+}
+
+impl RawRecords {
+    fn into_response<T: DeserializeOwned>(self) -> LenientResponse<Vec<T>> {
+        let (records, errors) = deserialize_records(self.0, None);
+        LenientResponse { records, errors }
+    }
+}
+
+fn deserialize_records<T: DeserializeOwned>(
+    values: Vec<serde_json::Value>,
+    direction: Option<Direction>,
+) -> (Vec<T>, Vec<RecordError>) {
+    let mut records = Vec::with_capacity(values.len());
+    let mut errors = Vec::new();
+
+    for (index, value) in values.into_iter().enumerate() {
+        match serde_path_to_error::deserialize::<_, T>(&value) {
+            Ok(record) => records.push(record),
+            Err(err) => errors.push(RecordError {
+                index,
+                direction,
+                reason: format!("{}: {}", err.path(), err.inner()),
+                raw: value,
+            }),
+        }
+    }
+
+    (records, errors)
+}
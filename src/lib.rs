@@ -1,8 +1,17 @@
+pub mod cache;
 pub mod client;
 pub mod deserialize;
 pub mod errors;
+pub mod geo;
+pub mod lenient;
+pub mod normalized;
 pub mod requests;
 pub mod responses;
+pub mod retry;
+pub mod service_day;
+pub mod stream;
 pub mod types;
+#[cfg(feature = "chrono-tz")]
+pub mod tz;
 
-pub use client::Client;
+pub use client::{Client, ClientBuilder, Endpoint};
@@ -0,0 +1,451 @@
+use chrono::{NaiveDateTime, NaiveTime, Weekday};
+use serde::{de, Deserialize, Deserializer};
+use std::{collections::HashMap, fmt, hash::Hash, marker::PhantomData, str::FromStr};
+
+use crate::{
+    responses::{
+        Alert, AlertDetour, AlertSummary, Arrivals, ArrivalsResponse, BusDetour, BusSchedule,
+        ElevatorOutage, ElevatorOutagesMeta, ElevatorOutagesResponse, Location, NextToArrive,
+        NextToArriveLeg, RailSchedule, RouteDetours, RouteStop, Train, TransitVehicle,
+        TransitViewAllResponse, TransitViewResponse,
+    },
+    types::{
+        AlertRoute, BroadStreetStop, BusRoute, LocationType, MarketFrankfordStop, NhslStop,
+        RegionalRailStop, RegionalRailsLine, ServiceType, Track, TrainStatus, TransportType,
+    },
+};
+
+/// Reads back the normalized form that the responses and types serialize to
+///
+/// The `Deserialize` implementations of the responses read SEPTA's responses, which use different
+/// field names, date formats and string encoded values than the serialized form. This is the
+/// companion of their `Serialize` implementations instead, so a serialized value deserializes
+/// back into the same value.
+///
+/// # Example
+///
+/// ```
+/// use septa_api::{normalized, responses::RailSchedule};
+///
+/// let schedule: RailSchedule = serde_json::from_str(
+///     r#"{"station": "Wawa", "sched_tm": "9:08 pm", "est_tm": "9:09 pm", "act_tm": "na"}"#,
+/// )?;
+///
+/// let serialized = serde_json::to_string(&schedule)?;
+/// let deserialized: RailSchedule = normalized::from_str(&serialized)?;
+///
+/// assert_eq!(deserialized, schedule);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub trait FromNormalized: Sized {
+    fn from_normalized<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// Deserializes a value of `T` from its normalized form with any serde format
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalized<T>(pub T);
+
+impl<'de, T: FromNormalized> Deserialize<'de> for Normalized<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::from_normalized(deserializer).map(Normalized)
+    }
+}
+
+/// Deserializes a value from the JSON it was serialized to
+pub fn from_str<T: FromNormalized>(s: &str) -> Result<T, serde_json::Error> {
+    serde_json::from_str::<Normalized<T>>(s).map(|normalized| normalized.0)
+}
+
+/// Deserializes a value from the [`serde_json::Value`] it was serialized to
+pub fn from_value<T: FromNormalized>(value: serde_json::Value) -> Result<T, serde_json::Error> {
+    serde_json::from_value::<Normalized<T>>(value).map(|normalized| normalized.0)
+}
+
+impl<T: FromNormalized> FromNormalized for Vec<T> {
+    fn from_normalized<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<Normalized<T>>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(|value| value.0).collect())
+    }
+}
+
+impl<T: FromNormalized> FromNormalized for Option<T> {
+    fn from_normalized<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Option::<Normalized<T>>::deserialize(deserializer)?;
+        Ok(value.map(|value| value.0))
+    }
+}
+
+impl<K: FromNormalized + Eq + Hash, V: FromNormalized> FromNormalized for HashMap<K, V> {
+    fn from_normalized<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(NormalizedMapVisitor(PhantomData))
+    }
+}
+
+struct NormalizedMapVisitor<K, V>(PhantomData<(K, V)>);
+
+impl<'de, K: FromNormalized + Eq + Hash, V: FromNormalized> de::Visitor<'de>
+    for NormalizedMapVisitor<K, V>
+{
+    type Value = HashMap<K, V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a map of normalized values")
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut result = HashMap::new();
+
+        while let Some((key, value)) = map.next_entry::<Normalized<K>, Normalized<V>>()? {
+            result.insert(key.0, value.0);
+        }
+
+        Ok(result)
+    }
+}
+
+/// The types whose `Deserialize` already reads the string they serialize to
+macro_rules! impl_from_normalized_with_deserialize {
+    ($($value_type:ty),*) => {
+        $(
+            impl FromNormalized for $value_type {
+                fn from_normalized<'de, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    <$value_type>::deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_normalized_with_deserialize!(
+    TransportType,
+    ServiceType,
+    RegionalRailsLine,
+    AlertRoute,
+    NhslStop,
+    MarketFrankfordStop,
+    BroadStreetStop,
+    LocationType,
+    BusRoute,
+    Track,
+    TrainStatus
+);
+
+/// The serialized `{"name": ..., "stop_id": ...}` form of a stop, the stop id is derived from the
+/// name so it is ignored
+#[derive(Deserialize)]
+struct NormalizedRegionalRailStop {
+    name: String,
+}
+
+impl FromNormalized for RegionalRailStop {
+    fn from_normalized<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let stop = NormalizedRegionalRailStop::deserialize(deserializer)?;
+        RegionalRailStop::from_str(&stop.name).map_err(de::Error::custom)
+    }
+}
+
+/// Implements [`FromNormalized`] for responses with a `#[serde(remote = ...)]` definition
+///
+/// serde builds the response out of the remote definition field by field, so a field that is added
+/// to, removed from or retyped on a response fails to compile until its definition here matches.
+macro_rules! impl_from_normalized_with_remote {
+    ($($response_type:ty => $remote_type:ty),*) => {
+        $(
+            impl FromNormalized for $response_type {
+                fn from_normalized<'de, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    <$remote_type>::deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_normalized_with_remote!(
+    ArrivalsResponse => NormalizedArrivalsResponse,
+    Arrivals => NormalizedArrivals,
+    Train => NormalizedTrain,
+    RailSchedule => NormalizedRailSchedule,
+    TransitViewResponse => NormalizedTransitViewResponse,
+    TransitViewAllResponse => NormalizedTransitViewAllResponse,
+    TransitVehicle => NormalizedTransitVehicle,
+    AlertSummary => NormalizedAlertSummary,
+    Alert => NormalizedAlert,
+    AlertDetour => NormalizedAlertDetour,
+    BusSchedule => NormalizedBusSchedule,
+    Location => NormalizedLocation,
+    ElevatorOutagesResponse => NormalizedElevatorOutagesResponse,
+    ElevatorOutagesMeta => NormalizedElevatorOutagesMeta,
+    ElevatorOutage => NormalizedElevatorOutage,
+    RouteDetours => NormalizedRouteDetours,
+    BusDetour => NormalizedBusDetour,
+    RouteStop => NormalizedRouteStop,
+    NextToArrive => NormalizedNextToArrive,
+    NextToArriveLeg => NormalizedNextToArriveLeg
+);
+
+#[derive(Deserialize)]
+#[serde(remote = "ArrivalsResponse")]
+struct NormalizedArrivalsResponse {
+    title: String,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    northbound: Vec<Arrivals>,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    southbound: Vec<Arrivals>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "Arrivals")]
+struct NormalizedArrivals {
+    direction: String,
+    path: String,
+    train_id: String,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    origin: RegionalRailStop,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    destination: RegionalRailStop,
+
+    line: Option<RegionalRailsLine>,
+
+    status: TrainStatus,
+
+    service_type: ServiceType,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    next_station: Option<RegionalRailStop>,
+
+    sched_time: NaiveDateTime,
+    depart_time: NaiveDateTime,
+    track: Option<Track>,
+    track_change: Option<Track>,
+    platform: Option<Track>,
+    platform_change: Option<Track>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "Train")]
+struct NormalizedTrain {
+    lat: f64,
+    lon: f64,
+    train_number: String,
+
+    service: ServiceType,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    dest: RegionalRailStop,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    current_stop: RegionalRailStop,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    next_stop: RegionalRailStop,
+
+    line: RegionalRailsLine,
+
+    consist: Option<Vec<i32>>,
+    heading: Option<f64>,
+    late: i32,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    source: RegionalRailStop,
+
+    track: Option<Track>,
+    track_change: Option<Track>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "NextToArrive", tag = "type", rename_all = "snake_case")]
+enum NormalizedNextToArrive {
+    Direct(#[serde(deserialize_with = "FromNormalized::from_normalized")] NextToArriveLeg),
+
+    WithConnection {
+        #[serde(deserialize_with = "FromNormalized::from_normalized")]
+        first_leg: NextToArriveLeg,
+
+        #[serde(deserialize_with = "FromNormalized::from_normalized")]
+        connection: RegionalRailStop,
+
+        #[serde(deserialize_with = "FromNormalized::from_normalized")]
+        second_leg: NextToArriveLeg,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "NextToArriveLeg")]
+struct NormalizedNextToArriveLeg {
+    train: String,
+    line: RegionalRailsLine,
+    departure_time: NaiveTime,
+    arrival_time: NaiveTime,
+    delay: TrainStatus,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "RailSchedule")]
+struct NormalizedRailSchedule {
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    station: RegionalRailStop,
+
+    scheduled_time: NaiveTime,
+    estimated_time: NaiveTime,
+    actual_time: Option<NaiveTime>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "TransitViewResponse")]
+struct NormalizedTransitViewResponse {
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    vehicles: Vec<TransitVehicle>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "TransitViewAllResponse")]
+struct NormalizedTransitViewAllResponse {
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    routes: HashMap<BusRoute, Vec<TransitVehicle>>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "TransitVehicle")]
+struct NormalizedTransitVehicle {
+    lat: f64,
+    lon: f64,
+    label: String,
+    vehicle_id: String,
+    block_id: String,
+    direction: String,
+    destination: Option<String>,
+    trip: Option<String>,
+    heading: Option<f64>,
+    offset: i32,
+    late: i32,
+    next_stop_name: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "AlertSummary")]
+struct NormalizedAlertSummary {
+    route: AlertRoute,
+    route_name: String,
+    mode: String,
+    is_advisory: bool,
+    is_detour: bool,
+    is_alert: bool,
+    is_suspended: bool,
+    last_updated: Option<NaiveDateTime>,
+    is_snow: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "Alert")]
+struct NormalizedAlert {
+    route: AlertRoute,
+    route_name: String,
+    current_message: Option<String>,
+    advisory_message: Option<String>,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    detour: Option<AlertDetour>,
+
+    last_updated: Option<NaiveDateTime>,
+    is_snow: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "AlertDetour")]
+struct NormalizedAlertDetour {
+    id: Option<String>,
+    message: Option<String>,
+    start_location: Option<String>,
+    start_date_time: Option<NaiveDateTime>,
+    end_date_time: Option<NaiveDateTime>,
+    reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "BusSchedule")]
+struct NormalizedBusSchedule {
+    stop_name: String,
+    route: BusRoute,
+    day: Weekday,
+    direction: String,
+    direction_description: String,
+    scheduled_time: NaiveDateTime,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "Location")]
+struct NormalizedLocation {
+    id: String,
+    name: String,
+    lat: f64,
+    lon: f64,
+    distance: f64,
+    location_type: LocationType,
+    data: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "ElevatorOutagesResponse")]
+struct NormalizedElevatorOutagesResponse {
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    meta: ElevatorOutagesMeta,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    outages: Vec<ElevatorOutage>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "ElevatorOutagesMeta")]
+struct NormalizedElevatorOutagesMeta {
+    elevators_out: u32,
+    updated: NaiveDateTime,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "ElevatorOutage")]
+struct NormalizedElevatorOutage {
+    line: String,
+    station: String,
+    elevator: String,
+    message: String,
+    alternate_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "RouteDetours")]
+struct NormalizedRouteDetours {
+    route_id: BusRoute,
+
+    #[serde(deserialize_with = "FromNormalized::from_normalized")]
+    detours: Vec<BusDetour>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "BusDetour")]
+struct NormalizedBusDetour {
+    direction: Option<String>,
+    reason: Option<String>,
+    start_location: Option<String>,
+    end_location: Option<String>,
+    start_date_time: Option<NaiveDateTime>,
+    end_date_time: Option<NaiveDateTime>,
+    current_message: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "RouteStop")]
+struct NormalizedRouteStop {
+    stop_id: u32,
+    name: String,
+    lat: f64,
+    lon: f64,
+    sequence: Option<u32>,
+}
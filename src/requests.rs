@@ -1,10 +1,10 @@
-use crate::types::RegionalRailStop;
+use crate::types::{AlertRoute, BusRoute, LocationType, RegionalRailStop};
 
 pub trait Request {
     fn into_params(self) -> Vec<(&'static str, String)>;
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Direction {
     North,
     South,
@@ -19,6 +19,22 @@ impl std::fmt::Display for Direction {
     }
 }
 
+/// The direction of a bus or trolley relative to Center City
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BusDirection {
+    Inbound,
+    Outbound,
+}
+
+impl std::fmt::Display for BusDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Inbound => write!(f, "i"),
+            Self::Outbound => write!(f, "o"),
+        }
+    }
+}
+
 pub struct ArrivalsRequest {
     pub station: RegionalRailStop,
     pub results: Option<u8>,
@@ -73,3 +89,100 @@ impl Request for RailScheduleRequest {
         vec![("req1", self.train_number)]
     }
 }
+
+pub struct TransitViewRequest {
+    pub route: BusRoute,
+}
+
+impl Request for TransitViewRequest {
+    fn into_params(self) -> Vec<(&'static str, String)> {
+        vec![("route", self.route.to_string())]
+    }
+}
+
+pub struct AlertsRequest {
+    pub route: Option<AlertRoute>,
+}
+
+impl Request for AlertsRequest {
+    fn into_params(self) -> Vec<(&'static str, String)> {
+        let route = match self.route {
+            Some(route) => route.to_string(),
+            None => "all".to_string(),
+        };
+
+        vec![("req1", route)]
+    }
+}
+
+pub struct BusSchedulesRequest {
+    /// The GTFS stop id of the bus or trolley stop
+    pub stop_id: u32,
+    pub route: Option<BusRoute>,
+    pub direction: Option<BusDirection>,
+    pub results: Option<u8>,
+}
+
+impl Request for BusSchedulesRequest {
+    fn into_params(self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+
+        params.push(("req1", self.stop_id.to_string()));
+
+        if let Some(route) = self.route {
+            params.push(("req2", route.to_string()));
+        }
+
+        if let Some(direction) = self.direction {
+            params.push(("req3", direction.to_string()));
+        }
+
+        if let Some(results) = self.results {
+            params.push(("req6", results.to_string()));
+        }
+
+        params
+    }
+}
+
+pub struct LocationsRequest {
+    pub lat: f64,
+    pub lon: f64,
+    /// The search radius in miles
+    pub radius: f64,
+    pub location_type: LocationType,
+}
+
+impl Request for LocationsRequest {
+    fn into_params(self) -> Vec<(&'static str, String)> {
+        vec![
+            ("lon", self.lon.to_string()),
+            ("lat", self.lat.to_string()),
+            ("radius", self.radius.to_string()),
+            ("type", self.location_type.to_string()),
+        ]
+    }
+}
+
+pub struct BusDetoursRequest {
+    pub route: Option<BusRoute>,
+}
+
+impl Request for BusDetoursRequest {
+    fn into_params(self) -> Vec<(&'static str, String)> {
+        match self.route {
+            Some(route) => vec![("req1", route.to_string())],
+            None => Vec::new(),
+        }
+    }
+}
+
+pub struct RouteStopsRequest {
+    pub route: BusRoute,
+}
+
+impl Request for RouteStopsRequest {
+    fn into_params(self) -> Vec<(&'static str, String)> {
+        vec![("req1", self.route.to_string())]
+    }
+}
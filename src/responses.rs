@@ -1,15 +1,25 @@
-use chrono::{NaiveDateTime, NaiveTime};
-use serde::{ser::Error, Deserialize};
-use std::{collections::HashMap, convert::TryFrom};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use serde::{ser::Error, Deserialize, Serialize};
+use std::{collections::HashMap, convert::TryFrom, str::FromStr};
+use strum::IntoEnumIterator;
 
 use crate::{
     deserialize::{
-        deserialize_api_error, deserialize_bool, deserialize_f64, deserialize_naive_date_time,
+        deserialize_api_error, deserialize_bool, deserialize_f64, deserialize_i32,
+        deserialize_naive_date_time, deserialize_naive_date_time_with_slashes,
         deserialize_naive_time, deserialize_naive_time_with_space,
-        deserialize_option_csv_encoded_string, deserialize_option_naive_time_with_space,
-        deserialize_optional_f64, deserialize_optional_string_enum, deserialize_string_enum,
+        deserialize_option_csv_encoded_string, deserialize_option_naive_date_time_with_month,
+        deserialize_option_naive_date_time_with_slashes, deserialize_option_naive_time_with_space,
+        deserialize_optional_f64, deserialize_optional_naive_time, deserialize_optional_string,
+        deserialize_optional_string_enum, deserialize_optional_track, deserialize_string_enum,
+        deserialize_train_status, deserialize_u32, deserialize_yes_no_bool,
+    },
+    requests::Direction,
+    service_day::{anchor, resolve_near},
+    types::{
+        AlertRoute, BusRoute, KnownRegionalRailStop, LocationType, NhslStop, RegionalRailStop,
+        RegionalRailsLine, ServiceType, Track, TrackAssignment, TrainStatus, TransportType,
     },
-    types::{RegionalRailStop, RegionalRailsLine, ServiceType},
 };
 
 #[derive(Debug, Deserialize)]
@@ -22,18 +32,27 @@ pub enum ApiResponse<T> {
 
 pub type ArrivalsApiResponse = ApiResponse<ArrivalsResponse>;
 
-#[derive(Debug, Deserialize, Clone)]
-#[serde(try_from = "ArrivalsResponseBuilder")]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(try_from = "RawArrivalsResponse")]
 pub struct ArrivalsResponse {
     pub title: String,
     pub northbound: Vec<Arrivals>,
     pub southbound: Vec<Arrivals>,
 }
 
+/// The arrivals response with the individual arrivals left as raw JSON
+#[derive(Debug, Deserialize, Clone)]
+#[serde(try_from = "ArrivalsResponseBuilder")]
+pub(crate) struct RawArrivalsResponse {
+    pub(crate) title: String,
+    pub(crate) northbound: Vec<serde_json::Value>,
+    pub(crate) southbound: Vec<serde_json::Value>,
+}
+
 #[derive(Debug, Deserialize)]
 struct ArrivalsResponseBuilder(HashMap<String, Vec<serde_json::Value>>);
 
-impl TryFrom<ArrivalsResponseBuilder> for ArrivalsResponse {
+impl TryFrom<ArrivalsResponseBuilder> for RawArrivalsResponse {
     type Error = serde_json::Error;
 
     fn try_from(builder: ArrivalsResponseBuilder) -> Result<Self, Self::Error> {
@@ -65,10 +84,12 @@ impl TryFrom<ArrivalsResponseBuilder> for ArrivalsResponse {
                 }
             }
 
-            let mut inner_values = serde_json::from_value::<HashMap<String, Vec<Arrivals>>>(value)
-                .map_err(|err| {
-                    serde_json::Error::custom(format!("Could not parse inner values: {}", err))
-                })?;
+            let mut inner_values =
+                serde_json::from_value::<HashMap<String, Vec<serde_json::Value>>>(value).map_err(
+                    |err| {
+                        serde_json::Error::custom(format!("Could not parse inner values: {}", err))
+                    },
+                )?;
 
             if let Some(northbound_values) = inner_values.remove("Northbound") {
                 northbound = match northbound {
@@ -89,7 +110,7 @@ impl TryFrom<ArrivalsResponseBuilder> for ArrivalsResponse {
             }
         }
 
-        Ok(ArrivalsResponse {
+        Ok(RawArrivalsResponse {
             title,
             northbound: northbound.unwrap_or_default(),
             southbound: southbound.unwrap_or_default(),
@@ -97,7 +118,29 @@ impl TryFrom<ArrivalsResponseBuilder> for ArrivalsResponse {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+impl TryFrom<RawArrivalsResponse> for ArrivalsResponse {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawArrivalsResponse) -> Result<Self, Self::Error> {
+        let parse_arrivals = |values: Vec<serde_json::Value>| {
+            values
+                .into_iter()
+                .map(serde_json::from_value::<Arrivals>)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| {
+                    serde_json::Error::custom(format!("Could not parse inner values: {}", err))
+                })
+        };
+
+        Ok(ArrivalsResponse {
+            title: raw.title,
+            northbound: parse_arrivals(raw.northbound)?,
+            southbound: parse_arrivals(raw.southbound)?,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Arrivals {
     pub direction: String,
     pub path: String,
@@ -111,7 +154,9 @@ pub struct Arrivals {
 
     #[serde(deserialize_with = "deserialize_optional_string_enum")]
     pub line: Option<RegionalRailsLine>,
-    pub status: String,
+
+    #[serde(deserialize_with = "deserialize_train_status")]
+    pub status: TrainStatus,
 
     #[serde(deserialize_with = "deserialize_string_enum")]
     pub service_type: ServiceType,
@@ -124,16 +169,36 @@ pub struct Arrivals {
 
     #[serde(deserialize_with = "deserialize_naive_date_time")]
     pub depart_time: NaiveDateTime,
-    pub track: String,
-    pub track_change: Option<String>,
-    pub platform: String,
-    pub platform_change: Option<String>,
+
+    #[serde(deserialize_with = "deserialize_optional_track")]
+    pub track: Option<Track>,
+
+    #[serde(deserialize_with = "deserialize_optional_track")]
+    pub track_change: Option<Track>,
+
+    #[serde(deserialize_with = "deserialize_optional_track")]
+    pub platform: Option<Track>,
+
+    #[serde(deserialize_with = "deserialize_optional_track")]
+    pub platform_change: Option<Track>,
+}
+
+impl Arrivals {
+    /// Returns the scheduled track and the track it was changed to
+    pub fn track_assignment(&self) -> TrackAssignment {
+        TrackAssignment::new(self.track.clone(), self.track_change.clone())
+    }
+
+    /// Returns the scheduled platform and the platform it was changed to
+    pub fn platform_assignment(&self) -> TrackAssignment {
+        TrackAssignment::new(self.platform.clone(), self.platform_change.clone())
+    }
 }
 
 pub type TrainApiResponse = ApiResponse<TrainResponse>;
 pub type TrainResponse = Vec<Train>;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Train {
     #[serde(deserialize_with = "deserialize_f64")]
     pub lat: f64,
@@ -141,7 +206,7 @@ pub struct Train {
     #[serde(deserialize_with = "deserialize_f64")]
     pub lon: f64,
 
-    #[serde(rename = "trainno")]
+    #[serde(rename(deserialize = "trainno"))]
     pub train_number: String,
 
     #[serde(deserialize_with = "deserialize_string_enum")]
@@ -150,10 +215,16 @@ pub struct Train {
     #[serde(deserialize_with = "deserialize_string_enum")]
     pub dest: RegionalRailStop,
 
-    #[serde(rename = "currentstop", deserialize_with = "deserialize_string_enum")]
+    #[serde(
+        rename(deserialize = "currentstop"),
+        deserialize_with = "deserialize_string_enum"
+    )]
     pub current_stop: RegionalRailStop,
 
-    #[serde(rename = "nextstop", deserialize_with = "deserialize_string_enum")]
+    #[serde(
+        rename(deserialize = "nextstop"),
+        deserialize_with = "deserialize_string_enum"
+    )]
     pub next_stop: RegionalRailStop,
 
     #[serde(deserialize_with = "deserialize_string_enum")]
@@ -167,61 +238,776 @@ pub struct Train {
 
     pub late: i32,
 
-    #[serde(rename = "SOURCE", deserialize_with = "deserialize_string_enum")]
+    #[serde(
+        rename(deserialize = "SOURCE"),
+        deserialize_with = "deserialize_string_enum"
+    )]
     pub source: RegionalRailStop,
 
-    #[serde(rename = "TRACK")]
-    pub track: String,
+    #[serde(
+        rename(deserialize = "TRACK"),
+        deserialize_with = "deserialize_optional_track"
+    )]
+    pub track: Option<Track>,
+
+    #[serde(
+        rename(deserialize = "TRACK_CHANGE"),
+        deserialize_with = "deserialize_optional_track"
+    )]
+    pub track_change: Option<Track>,
+}
 
-    #[serde(rename = "TRACK_CHANGE")]
-    pub track_change: String,
+impl Train {
+    /// Returns the scheduled track and the track it was changed to
+    pub fn track_assignment(&self) -> TrackAssignment {
+        TrackAssignment::new(self.track.clone(), self.track_change.clone())
+    }
 }
 
 pub type NextToArriveApiResponse = ApiResponse<NextToArriveResponse>;
 pub type NextToArriveResponse = Vec<NextToArrive>;
 
-#[derive(Debug, Deserialize, Clone)]
-pub struct NextToArrive {
-    pub orig_train: String,
+/// A trip from the starting station to the ending station of a
+/// [`crate::requests::NextToArriveRequest`]
+///
+/// The trip is serialized by its kind, such as `{"type": "direct", "train": ...}` for a direct trip
+/// and `{"type": "with_connection", "first_leg": ..., "connection": ..., "second_leg": ...}` for a
+/// trip with a connection, rather than SEPTA's flat `orig_*` and `term_*` fields.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(
+    try_from = "NextToArriveBuilder",
+    tag = "type",
+    rename_all = "snake_case"
+)]
+pub enum NextToArrive {
+    /// A single train runs from the starting station to the ending station
+    Direct(NextToArriveLeg),
+
+    /// The rider changes trains at `connection`
+    WithConnection {
+        first_leg: NextToArriveLeg,
+        connection: RegionalRailStop,
+        second_leg: NextToArriveLeg,
+    },
+}
+
+/// One train of a [`NextToArrive`] trip
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct NextToArriveLeg {
+    pub train: String,
+    pub line: RegionalRailsLine,
+    pub departure_time: NaiveTime,
+    pub arrival_time: NaiveTime,
+    pub delay: TrainStatus,
+}
+
+impl NextToArrive {
+    /// Returns the leg departing from the starting station
+    pub fn first_leg(&self) -> &NextToArriveLeg {
+        match self {
+            Self::Direct(leg) => leg,
+            Self::WithConnection { first_leg, .. } => first_leg,
+        }
+    }
+
+    /// Returns the leg arriving at the ending station
+    pub fn last_leg(&self) -> &NextToArriveLeg {
+        match self {
+            Self::Direct(leg) => leg,
+            Self::WithConnection { second_leg, .. } => second_leg,
+        }
+    }
+
+    pub fn is_direct(&self) -> bool {
+        matches!(self, Self::Direct(_))
+    }
+
+    /// Returns the departure and arrival of every leg anchored to the service day starting on
+    /// `service_date`
+    ///
+    /// Each time is resolved relative to the time before it, so a trip departing at 11:49 PM and
+    /// arriving at 12:33 AM arrives on the next calendar day.
+    pub fn leg_date_times(&self, service_date: NaiveDate) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+        let legs = match self {
+            Self::Direct(leg) => vec![leg],
+            Self::WithConnection {
+                first_leg,
+                second_leg,
+                ..
+            } => vec![first_leg, second_leg],
+        };
+
+        let mut previous = anchor(service_date, self.first_leg().departure_time);
+
+        legs.into_iter()
+            .map(|leg| {
+                let departure = resolve_near(previous, leg.departure_time);
+                let arrival = resolve_near(departure, leg.arrival_time);
+                previous = arrival;
+                (departure, arrival)
+            })
+            .collect()
+    }
+
+    /// Returns the departure from the starting station anchored to the service day starting on
+    /// `service_date`
+    pub fn departure_date_time(&self, service_date: NaiveDate) -> NaiveDateTime {
+        anchor(service_date, self.first_leg().departure_time)
+    }
+
+    /// Returns the arrival at the ending station anchored to the service day starting on
+    /// `service_date`, see [`NextToArrive::leg_date_times`]
+    pub fn arrival_date_time(&self, service_date: NaiveDate) -> NaiveDateTime {
+        let (_, arrival) = *self
+            .leg_date_times(service_date)
+            .last()
+            .expect("a trip has at least one leg");
+        arrival
+    }
+}
+
+/// The flat form of [`NextToArrive`] that SEPTA sends, connections are only set for indirect trips
+#[derive(Debug, Deserialize)]
+struct NextToArriveBuilder {
+    orig_train: String,
 
     #[serde(deserialize_with = "deserialize_string_enum")]
-    pub orig_line: RegionalRailsLine,
+    orig_line: RegionalRailsLine,
 
     #[serde(deserialize_with = "deserialize_naive_time")]
-    pub orig_departure_time: NaiveTime,
+    orig_departure_time: NaiveTime,
 
     #[serde(deserialize_with = "deserialize_naive_time")]
-    pub orig_arrival_time: NaiveTime,
+    orig_arrival_time: NaiveTime,
 
-    pub orig_delay: String,
+    #[serde(deserialize_with = "deserialize_train_status")]
+    orig_delay: TrainStatus,
 
-    #[serde(rename = "isdirect", deserialize_with = "deserialize_bool")]
-    pub is_direct: bool,
+    #[serde(
+        rename(deserialize = "isdirect"),
+        deserialize_with = "deserialize_bool"
+    )]
+    is_direct: bool,
+
+    #[serde(
+        rename(deserialize = "Connection"),
+        default,
+        deserialize_with = "deserialize_optional_string_enum"
+    )]
+    connection: Option<RegionalRailStop>,
+
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    term_train: Option<String>,
+
+    #[serde(default, deserialize_with = "deserialize_optional_string_enum")]
+    term_line: Option<RegionalRailsLine>,
+
+    #[serde(default, deserialize_with = "deserialize_optional_naive_time")]
+    term_depart_time: Option<NaiveTime>,
+
+    #[serde(default, deserialize_with = "deserialize_optional_naive_time")]
+    term_arrival_time: Option<NaiveTime>,
+
+    #[serde(default)]
+    term_delay: Option<TrainStatus>,
+}
+
+impl TryFrom<NextToArriveBuilder> for NextToArrive {
+    type Error = String;
+
+    fn try_from(builder: NextToArriveBuilder) -> Result<Self, Self::Error> {
+        let first_leg = NextToArriveLeg {
+            train: builder.orig_train,
+            line: builder.orig_line,
+            departure_time: builder.orig_departure_time,
+            arrival_time: builder.orig_arrival_time,
+            delay: builder.orig_delay,
+        };
+
+        if builder.is_direct {
+            return Ok(Self::Direct(first_leg));
+        }
+
+        let missing = |field: &str| format!("indirect trip is missing {}", field);
+
+        Ok(Self::WithConnection {
+            first_leg,
+            connection: builder.connection.ok_or_else(|| missing("Connection"))?,
+            second_leg: NextToArriveLeg {
+                train: builder.term_train.ok_or_else(|| missing("term_train"))?,
+                line: builder.term_line.ok_or_else(|| missing("term_line"))?,
+                departure_time: builder
+                    .term_depart_time
+                    .ok_or_else(|| missing("term_depart_time"))?,
+                arrival_time: builder
+                    .term_arrival_time
+                    .ok_or_else(|| missing("term_arrival_time"))?,
+                delay: builder.term_delay.ok_or_else(|| missing("term_delay"))?,
+            },
+        })
+    }
 }
 
 pub type RailScheduleApiResponse = ApiResponse<RailScheduleResponse>;
 pub type RailScheduleResponse = Vec<RailSchedule>;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct RailSchedule {
     #[serde(deserialize_with = "deserialize_string_enum")]
     pub station: RegionalRailStop,
 
     #[serde(
-        rename = "sched_tm",
+        rename(deserialize = "sched_tm"),
         deserialize_with = "deserialize_naive_time_with_space"
     )]
     pub scheduled_time: NaiveTime,
 
     #[serde(
-        rename = "est_tm",
+        rename(deserialize = "est_tm"),
         deserialize_with = "deserialize_naive_time_with_space"
     )]
     pub estimated_time: NaiveTime,
 
     #[serde(
-        rename = "act_tm",
+        rename(deserialize = "act_tm"),
         deserialize_with = "deserialize_option_naive_time_with_space"
     )]
     pub actual_time: Option<NaiveTime>,
 }
+
+impl RailSchedule {
+    /// Returns the scheduled time anchored to the service day starting on `service_date`
+    pub fn scheduled_date_time(&self, service_date: NaiveDate) -> NaiveDateTime {
+        anchor(service_date, self.scheduled_time)
+    }
+
+    /// Returns the estimated time anchored to the service day starting on `service_date`
+    ///
+    /// The estimate is resolved relative to the scheduled time, so a train scheduled for 11:50 PM
+    /// and estimated for 12:10 AM is estimated for the next calendar day.
+    pub fn estimated_date_time(&self, service_date: NaiveDate) -> NaiveDateTime {
+        resolve_near(self.scheduled_date_time(service_date), self.estimated_time)
+    }
+
+    /// Returns the actual time anchored to the service day starting on `service_date`, resolved
+    /// relative to the scheduled time like [`RailSchedule::estimated_date_time`]
+    pub fn actual_date_time(&self, service_date: NaiveDate) -> Option<NaiveDateTime> {
+        self.actual_time
+            .map(|actual_time| resolve_near(self.scheduled_date_time(service_date), actual_time))
+    }
+
+    /// Returns how late the train is at the stop, negative when it is early
+    ///
+    /// The delay is measured from the scheduled time to the actual time once the train has
+    /// reached the stop, or to the estimated time before then.
+    pub fn delay(&self) -> Duration {
+        // Any service date works since only the difference between the times is needed
+        let service_date = NaiveDate::default();
+        let scheduled = self.scheduled_date_time(service_date);
+        let latest = self
+            .actual_date_time(service_date)
+            .unwrap_or_else(|| self.estimated_date_time(service_date));
+
+        latest - scheduled
+    }
+}
+
+pub type TransitViewApiResponse = ApiResponse<TransitViewResponse>;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct TransitViewResponse {
+    #[serde(rename(deserialize = "bus"))]
+    pub vehicles: Vec<TransitVehicle>,
+}
+
+pub type TransitViewAllApiResponse = ApiResponse<TransitViewAllResponse>;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(try_from = "TransitViewAllResponseBuilder")]
+pub struct TransitViewAllResponse {
+    pub routes: HashMap<BusRoute, Vec<TransitVehicle>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TransitViewAllResponseBuilder {
+    routes: Vec<HashMap<BusRoute, Vec<TransitVehicle>>>,
+}
+
+impl TryFrom<TransitViewAllResponseBuilder> for TransitViewAllResponse {
+    type Error = serde_json::Error;
+
+    fn try_from(builder: TransitViewAllResponseBuilder) -> Result<Self, Self::Error> {
+        let mut routes = HashMap::new();
+
+        // SEPTA wraps the route map in an array, it has only ever been observed with a single
+        // element but merge them all just in case
+        for route_map in builder.routes.into_iter() {
+            for (route, vehicles) in route_map.into_iter() {
+                if routes.insert(route.clone(), vehicles).is_some() {
+                    return Err(serde_json::Error::custom(format!(
+                        "Found two values for route {}",
+                        route
+                    )));
+                }
+            }
+        }
+
+        Ok(TransitViewAllResponse { routes })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct TransitVehicle {
+    #[serde(deserialize_with = "deserialize_f64")]
+    pub lat: f64,
+
+    #[serde(rename(deserialize = "lng"), deserialize_with = "deserialize_f64")]
+    pub lon: f64,
+
+    pub label: String,
+
+    #[serde(rename(deserialize = "VehicleID"))]
+    pub vehicle_id: String,
+
+    #[serde(rename(deserialize = "BlockID"))]
+    pub block_id: String,
+
+    #[serde(rename(deserialize = "Direction"))]
+    pub direction: String,
+
+    pub destination: Option<String>,
+
+    pub trip: Option<String>,
+
+    #[serde(default, deserialize_with = "deserialize_optional_f64")]
+    pub heading: Option<f64>,
+
+    #[serde(rename(deserialize = "Offset"), deserialize_with = "deserialize_i32")]
+    pub offset: i32,
+
+    pub late: i32,
+
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub next_stop_name: Option<String>,
+}
+
+impl TransitVehicle {
+    /// Returns the direction of an NHSL vehicle, `None` if it is not heading north or south
+    ///
+    /// # Arguments
+    ///
+    /// * `route` - The route the vehicle was returned for, `None` is returned unless it is the
+    ///   NHSL
+    pub fn nhsl_direction(&self, route: &BusRoute) -> Option<Direction> {
+        if route.transport_type() != TransportType::Nhsl {
+            return None;
+        }
+
+        match self.direction.to_ascii_lowercase().as_str() {
+            "northbound" => Some(Direction::North),
+            "southbound" => Some(Direction::South),
+            _ => None,
+        }
+    }
+
+    /// Returns the NHSL stop the vehicle is heading to
+    ///
+    /// # Arguments
+    ///
+    /// * `route` - The route the vehicle was returned for, `None` is returned unless it is the
+    ///   NHSL since other routes stop at places that share their name with an NHSL stop
+    pub fn nhsl_next_stop(&self, route: &BusRoute) -> Option<NhslStop> {
+        if route.transport_type() != TransportType::Nhsl {
+            return None;
+        }
+
+        self.next_stop_name.as_deref().and_then(NhslStop::from_name)
+    }
+
+    /// Returns the NHSL stop before the vehicle's next stop in its direction of travel
+    ///
+    /// SEPTA only reports the next stop, so this does not tell whether the vehicle is still
+    /// standing at the returned stop or already between it and the next one. `None` is returned
+    /// when the next stop is the first stop of the line or is unknown, see
+    /// [`TransitVehicle::nhsl_next_stop`].
+    pub fn nhsl_current_stop(&self, route: &BusRoute) -> Option<NhslStop> {
+        self.nhsl_next_stop(route)?
+            .previous_stop(self.nhsl_direction(route)?)
+    }
+}
+
+pub type AlertsSummaryApiResponse = ApiResponse<AlertsSummaryResponse>;
+pub type AlertsSummaryResponse = Vec<AlertSummary>;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct AlertSummary {
+    #[serde(
+        rename(deserialize = "route_id"),
+        deserialize_with = "deserialize_string_enum"
+    )]
+    pub route: AlertRoute,
+
+    pub route_name: String,
+
+    pub mode: String,
+
+    #[serde(
+        rename(deserialize = "isadvisory"),
+        deserialize_with = "deserialize_yes_no_bool"
+    )]
+    pub is_advisory: bool,
+
+    #[serde(
+        rename(deserialize = "isdetour"),
+        deserialize_with = "deserialize_yes_no_bool"
+    )]
+    pub is_detour: bool,
+
+    #[serde(
+        rename(deserialize = "isalert"),
+        deserialize_with = "deserialize_yes_no_bool"
+    )]
+    pub is_alert: bool,
+
+    #[serde(
+        rename(deserialize = "issuppend"),
+        deserialize_with = "deserialize_yes_no_bool"
+    )]
+    pub is_suspended: bool,
+
+    #[serde(deserialize_with = "deserialize_option_naive_date_time_with_month")]
+    pub last_updated: Option<NaiveDateTime>,
+
+    #[serde(
+        rename(deserialize = "isSnow"),
+        deserialize_with = "deserialize_yes_no_bool"
+    )]
+    pub is_snow: bool,
+}
+
+pub type AlertsApiResponse = ApiResponse<AlertsResponse>;
+pub type AlertsResponse = Vec<Alert>;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(from = "AlertBuilder")]
+pub struct Alert {
+    pub route: AlertRoute,
+    pub route_name: String,
+    pub current_message: Option<String>,
+    pub advisory_message: Option<String>,
+    pub detour: Option<AlertDetour>,
+    pub last_updated: Option<NaiveDateTime>,
+    pub is_snow: bool,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct AlertDetour {
+    pub id: Option<String>,
+    pub message: Option<String>,
+    pub start_location: Option<String>,
+    pub start_date_time: Option<NaiveDateTime>,
+    pub end_date_time: Option<NaiveDateTime>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AlertBuilder {
+    #[serde(deserialize_with = "deserialize_string_enum")]
+    route_id: AlertRoute,
+
+    route_name: String,
+
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    current_message: Option<String>,
+
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    advisory_message: Option<String>,
+
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    detour_message: Option<String>,
+
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    detour_id: Option<String>,
+
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    detour_start_location: Option<String>,
+
+    #[serde(deserialize_with = "deserialize_option_naive_date_time_with_slashes")]
+    detour_start_date_time: Option<NaiveDateTime>,
+
+    #[serde(deserialize_with = "deserialize_option_naive_date_time_with_slashes")]
+    detour_end_date_time: Option<NaiveDateTime>,
+
+    #[serde(deserialize_with = "deserialize_optional_string")]
+    detour_reason: Option<String>,
+
+    #[serde(deserialize_with = "deserialize_option_naive_date_time_with_month")]
+    last_updated: Option<NaiveDateTime>,
+
+    #[serde(rename = "isSnow", deserialize_with = "deserialize_yes_no_bool")]
+    is_snow: bool,
+}
+
+impl From<AlertBuilder> for Alert {
+    fn from(builder: AlertBuilder) -> Self {
+        let detour = AlertDetour {
+            id: builder.detour_id,
+            message: builder.detour_message,
+            start_location: builder.detour_start_location,
+            start_date_time: builder.detour_start_date_time,
+            end_date_time: builder.detour_end_date_time,
+            reason: builder.detour_reason,
+        };
+
+        // SEPTA always sends the detour fields, they are just empty when there is no detour
+        let has_detour = detour.id.is_some()
+            || detour.message.is_some()
+            || detour.start_location.is_some()
+            || detour.start_date_time.is_some()
+            || detour.end_date_time.is_some()
+            || detour.reason.is_some();
+
+        Alert {
+            route: builder.route_id,
+            route_name: builder.route_name,
+            current_message: builder.current_message,
+            advisory_message: builder.advisory_message,
+            detour: has_detour.then_some(detour),
+            last_updated: builder.last_updated,
+            is_snow: builder.is_snow,
+        }
+    }
+}
+
+pub type BusSchedulesApiResponse = ApiResponse<BusSchedulesResponse>;
+pub type BusSchedulesResponse = HashMap<BusRoute, Vec<BusSchedule>>;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct BusSchedule {
+    #[serde(rename(deserialize = "StopName"))]
+    pub stop_name: String,
+
+    #[serde(
+        rename(deserialize = "Route"),
+        deserialize_with = "deserialize_string_enum"
+    )]
+    pub route: BusRoute,
+
+    #[serde(deserialize_with = "deserialize_string_enum")]
+    pub day: Weekday,
+
+    #[serde(rename(deserialize = "Direction"))]
+    pub direction: String,
+
+    #[serde(rename(deserialize = "DirectionDesc"))]
+    pub direction_description: String,
+
+    #[serde(
+        rename(deserialize = "DateCalender"),
+        deserialize_with = "deserialize_naive_date_time_with_slashes"
+    )]
+    pub scheduled_time: NaiveDateTime,
+}
+
+pub type LocationsApiResponse = ApiResponse<LocationsResponse>;
+pub type LocationsResponse = Vec<Location>;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Location {
+    #[serde(rename(deserialize = "location_id"))]
+    pub id: String,
+
+    #[serde(rename(deserialize = "location_name"))]
+    pub name: String,
+
+    #[serde(
+        rename(deserialize = "location_lat"),
+        deserialize_with = "deserialize_f64"
+    )]
+    pub lat: f64,
+
+    #[serde(
+        rename(deserialize = "location_lon"),
+        deserialize_with = "deserialize_f64"
+    )]
+    pub lon: f64,
+
+    /// The distance from the searched point in miles
+    #[serde(deserialize_with = "deserialize_f64")]
+    pub distance: f64,
+
+    #[serde(deserialize_with = "deserialize_string_enum")]
+    pub location_type: LocationType,
+
+    /// Extra details that depend on the type of the location, such as the address and hours of
+    /// a sales location
+    #[serde(rename(deserialize = "location_data"), default)]
+    pub data: serde_json::Value,
+}
+
+impl Location {
+    /// Returns the regional rail stop of a rail station location
+    ///
+    /// The stop is looked up by its GTFS stop id and then by its name, `None` is returned for
+    /// locations that are not rail stations or that are not a known regional rail stop.
+    pub fn rail_stop(&self) -> Option<RegionalRailStop> {
+        if self.location_type != LocationType::RailStations {
+            return None;
+        }
+
+        let stop_by_id = self.id.parse::<u32>().ok().and_then(|stop_id| {
+            KnownRegionalRailStop::iter()
+                .find(|stop| stop.stop_id() == stop_id)
+                .map(RegionalRailStop::from)
+        });
+
+        match stop_by_id.or_else(|| RegionalRailStop::from_str(&self.name).ok()) {
+            Some(RegionalRailStop::Unknown(_)) | None => None,
+            stop => stop,
+        }
+    }
+}
+
+pub type ElevatorOutagesApiResponse = ApiResponse<ElevatorOutagesResponse>;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ElevatorOutagesResponse {
+    pub meta: ElevatorOutagesMeta,
+
+    #[serde(rename(deserialize = "results"))]
+    pub outages: Vec<ElevatorOutage>,
+}
+
+impl ElevatorOutagesResponse {
+    /// Returns the outages at a regional rail stop, see [`ElevatorOutage::rail_stop`]
+    pub fn outages_at(&self, stop: &RegionalRailStop) -> Vec<&ElevatorOutage> {
+        self.outages
+            .iter()
+            .filter(|outage| outage.is_at(stop))
+            .collect()
+    }
+
+    /// Returns whether any elevator at a regional rail stop is out of service
+    pub fn has_outage_at(&self, stop: &RegionalRailStop) -> bool {
+        self.outages.iter().any(|outage| outage.is_at(stop))
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ElevatorOutagesMeta {
+    pub elevators_out: u32,
+
+    #[serde(deserialize_with = "deserialize_naive_date_time")]
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ElevatorOutage {
+    pub line: String,
+    pub station: String,
+
+    /// Describes which elevator is out of service
+    pub elevator: String,
+
+    pub message: String,
+
+    /// A link to the alternate route to take while the elevator is out of service
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub alternate_url: Option<String>,
+}
+
+impl ElevatorOutage {
+    /// Returns the regional rail stop of the outage's station
+    ///
+    /// The stop is looked up by the station's name, `None` is returned for outages on other lines
+    /// and for stations that are not a known regional rail stop. Subway stations can share a
+    /// name with a regional rail stop, such as 30th Street Station on the Market-Frankford Line.
+    pub fn rail_stop(&self) -> Option<RegionalRailStop> {
+        if !self.line.trim().eq_ignore_ascii_case("Regional Rail") {
+            return None;
+        }
+
+        match RegionalRailStop::from_str(&self.station) {
+            Ok(RegionalRailStop::Unknown(_)) | Err(_) => None,
+            Ok(stop) => Some(stop),
+        }
+    }
+
+    /// Returns whether the outage is at a regional rail stop, see [`ElevatorOutage::rail_stop`]
+    pub fn is_at(&self, stop: &RegionalRailStop) -> bool {
+        self.rail_stop().as_ref() == Some(stop)
+    }
+}
+
+pub type BusDetoursApiResponse = ApiResponse<BusDetoursResponse>;
+pub type BusDetoursResponse = Vec<RouteDetours>;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct RouteDetours {
+    #[serde(deserialize_with = "deserialize_string_enum")]
+    pub route_id: BusRoute,
+
+    #[serde(rename(deserialize = "route_info"))]
+    pub detours: Vec<BusDetour>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct BusDetour {
+    #[serde(
+        rename(deserialize = "route_direction"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    pub direction: Option<String>,
+
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub reason: Option<String>,
+
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub start_location: Option<String>,
+
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub end_location: Option<String>,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_naive_date_time_with_slashes"
+    )]
+    pub start_date_time: Option<NaiveDateTime>,
+
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_naive_date_time_with_slashes"
+    )]
+    pub end_date_time: Option<NaiveDateTime>,
+
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub current_message: Option<String>,
+}
+
+pub type RouteStopsApiResponse = ApiResponse<RouteStopsResponse>;
+pub type RouteStopsResponse = Vec<RouteStop>;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct RouteStop {
+    /// The GTFS stop id of the stop
+    #[serde(rename(deserialize = "stopid"), deserialize_with = "deserialize_u32")]
+    pub stop_id: u32,
+
+    #[serde(rename(deserialize = "stopname"))]
+    pub name: String,
+
+    #[serde(deserialize_with = "deserialize_f64")]
+    pub lat: f64,
+
+    #[serde(rename(deserialize = "lng"), deserialize_with = "deserialize_f64")]
+    pub lon: f64,
+
+    /// The position of the stop along the route, starting at 1
+    ///
+    /// SEPTA lists the stops in order without numbering them, so [`crate::Client::route_stops`]
+    /// numbers them by their position in the response. It is `None` for stops deserialized from a
+    /// `/Stops` response directly.
+    #[serde(default)]
+    pub sequence: Option<u32>,
+}
@@ -0,0 +1,120 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// Describes how a failed request is retried
+///
+/// Only errors that are likely to be transient are retried, see [`crate::errors::Error::is_retryable`].
+/// The delay before retry `n` (starting at 1) is `initial_backoff * multiplier^(n - 1)`, capped at
+/// `max_backoff`. When jitter is enabled the delay is picked uniformly between zero and that value
+/// so that many clients failing at the same time do not all retry in lockstep.
+///
+/// # Example
+///
+/// ```
+/// use septa_api::retry::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(4)
+///     .with_initial_backoff(Duration::from_millis(250))
+///     .with_max_backoff(Duration::from_secs(5))
+///     .with_multiplier(2)
+///     .with_jitter(true);
+///
+/// assert_eq!(policy.max_attempts(), 4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: u32,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy that makes at most `max_attempts` attempts, including the first one
+    ///
+    /// The policy starts with a 500ms backoff that doubles after every attempt up to 30 seconds,
+    /// with jitter enabled.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2,
+            jitter: true,
+        }
+    }
+
+    /// Creates a policy that never retries
+    pub fn never() -> Self {
+        Self::new(1)
+    }
+
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the factor the backoff grows by after every attempt, a multiplier of 1 gives a
+    /// constant backoff
+    pub fn with_multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier.max(1);
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the largest delay that is waited after the failed attempt `attempt` (starting at 1)
+    pub fn max_delay(&self, attempt: u32) -> Duration {
+        let factor = self
+            .multiplier
+            .checked_pow(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+
+        self.initial_backoff
+            .checked_mul(factor)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+
+    /// Returns the delay to wait after the failed attempt `attempt` (starting at 1)
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let max_delay = self.max_delay(attempt);
+
+        if self.jitter {
+            max_delay.mul_f64(random_fraction())
+        } else {
+            max_delay
+        }
+    }
+}
+
+// A fresh `RandomState` is seeded randomly by the standard library, which is plenty of entropy
+// for spreading out retries without pulling in a random number generator
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u8(0);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,60 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+/// The time a SEPTA service day starts
+///
+/// Service runs past midnight, so times earlier than this belong to the end of the previous
+/// service day rather than the start of the next one.
+pub const SERVICE_DAY_START: NaiveTime = match NaiveTime::from_hms_opt(3, 0, 0) {
+    Some(time) => time,
+    None => panic!("3:00 AM is a valid time"),
+};
+
+/// Anchors a time of the service day starting on `service_date` to a calendar date and time
+///
+/// Times before [`SERVICE_DAY_START`] are after midnight, so they resolve onto the next calendar
+/// day.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, NaiveTime};
+/// use septa_api::service_day::anchor;
+///
+/// let service_date = NaiveDate::from_ymd_opt(2023, 6, 13).unwrap();
+///
+/// assert_eq!(
+///     anchor(service_date, NaiveTime::from_hms_opt(23, 50, 0).unwrap()),
+///     service_date.and_hms_opt(23, 50, 0).unwrap()
+/// );
+/// assert_eq!(
+///     anchor(service_date, NaiveTime::from_hms_opt(0, 10, 0).unwrap()),
+///     NaiveDate::from_ymd_opt(2023, 6, 14)
+///         .unwrap()
+///         .and_hms_opt(0, 10, 0)
+///         .unwrap()
+/// );
+/// ```
+pub fn anchor(service_date: NaiveDate, time: NaiveTime) -> NaiveDateTime {
+    if time < SERVICE_DAY_START {
+        (service_date + Duration::days(1)).and_time(time)
+    } else {
+        service_date.and_time(time)
+    }
+}
+
+/// Resolves a time onto the calendar date that puts it closest to `reference`
+///
+/// Estimated and actual times are never more than 12 hours from their scheduled time, so this
+/// resolves a `12:10 AM` actual time of a `11:50 PM` train onto the day after the scheduled time.
+pub fn resolve_near(reference: NaiveDateTime, time: NaiveTime) -> NaiveDateTime {
+    let same_day = reference.date().and_time(time);
+    let difference = same_day - reference;
+
+    if difference > Duration::hours(12) {
+        same_day - Duration::days(1)
+    } else if difference < Duration::hours(-12) {
+        same_day + Duration::days(1)
+    } else {
+        same_day
+    }
+}
@@ -0,0 +1,177 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+
+use futures_util::{stream, Stream};
+use tokio::time::MissedTickBehavior;
+
+use crate::{
+    client::{Client, Result},
+    responses::Train,
+};
+
+/// A change between two consecutive TrainView snapshots
+///
+/// Trains are matched between snapshots by [`Train::train_number`]. The change variants carry
+/// both the previous and the current state of the train.
+#[derive(Debug, Clone)]
+pub enum TrainViewEvent {
+    /// The train was not in the previous snapshot
+    TrainAppeared(Train),
+
+    /// The train is no longer in the snapshot, this is its last known state
+    TrainDisappeared(Train),
+
+    /// The latitude or longitude of the train changed
+    PositionUpdated { previous: Train, current: Train },
+
+    /// The number of minutes the train is late changed
+    LatenessChanged { previous: Train, current: Train },
+
+    /// The next stop of the train changed
+    NextStopChanged { previous: Train, current: Train },
+
+    /// The track or the track change of the train changed
+    TrackChanged { previous: Train, current: Train },
+}
+
+impl TrainViewEvent {
+    /// Returns the number of the train the event is about
+    pub fn train_number(&self) -> &str {
+        match self {
+            Self::TrainAppeared(train) | Self::TrainDisappeared(train) => &train.train_number,
+            Self::PositionUpdated { current, .. }
+            | Self::LatenessChanged { current, .. }
+            | Self::NextStopChanged { current, .. }
+            | Self::TrackChanged { current, .. } => &current.train_number,
+        }
+    }
+
+    /// Returns the events needed to go from the `previous` snapshot to the `current` one
+    ///
+    /// Events for trains in `current` come first, in snapshot order, followed by the trains that
+    /// disappeared in the order they were in `previous`. A train that changed in several ways
+    /// produces one event per change.
+    pub fn diff(previous: &[Train], current: &[Train]) -> Vec<TrainViewEvent> {
+        let previous_trains = previous
+            .iter()
+            .map(|train| (train.train_number.as_str(), train))
+            .collect::<HashMap<&str, &Train>>();
+
+        let current_trains = current
+            .iter()
+            .map(|train| (train.train_number.as_str(), train))
+            .collect::<HashMap<&str, &Train>>();
+
+        let mut events = Vec::new();
+
+        for current_train in current {
+            let previous_train = match previous_trains.get(current_train.train_number.as_str()) {
+                Some(previous_train) => *previous_train,
+                None => {
+                    events.push(Self::TrainAppeared(current_train.clone()));
+                    continue;
+                }
+            };
+
+            if previous_train.lat != current_train.lat || previous_train.lon != current_train.lon {
+                events.push(Self::PositionUpdated {
+                    previous: previous_train.clone(),
+                    current: current_train.clone(),
+                });
+            }
+
+            if previous_train.late != current_train.late {
+                events.push(Self::LatenessChanged {
+                    previous: previous_train.clone(),
+                    current: current_train.clone(),
+                });
+            }
+
+            if previous_train.next_stop != current_train.next_stop {
+                events.push(Self::NextStopChanged {
+                    previous: previous_train.clone(),
+                    current: current_train.clone(),
+                });
+            }
+
+            if previous_train.track_assignment() != current_train.track_assignment() {
+                events.push(Self::TrackChanged {
+                    previous: previous_train.clone(),
+                    current: current_train.clone(),
+                });
+            }
+        }
+
+        for previous_train in previous {
+            if !current_trains.contains_key(previous_train.train_number.as_str()) {
+                events.push(Self::TrainDisappeared(previous_train.clone()));
+            }
+        }
+
+        events
+    }
+}
+
+/// The shortest time between polls, shorter intervals are clamped to it
+const MIN_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+struct TrainViewStreamState {
+    client: Client,
+    period: Duration,
+    interval: Option<tokio::time::Interval>,
+    snapshot: Option<Vec<Train>>,
+    pending: VecDeque<Result<TrainViewEvent>>,
+}
+
+/// Polls TrainView every `interval` and yields the changes between consecutive snapshots
+///
+/// The first successful poll yields a [`TrainViewEvent::TrainAppeared`] for every train. A failed
+/// poll yields the error and the stream keeps polling, diffing the next successful snapshot
+/// against the last successful one. The stream never ends on its own.
+///
+/// The timer is only created once the stream is first polled, so the stream can be built outside
+/// of a runtime. An `interval` shorter than [`MIN_POLL_INTERVAL`] is clamped to it.
+pub(crate) fn train_view_events(
+    client: Client,
+    interval: Duration,
+) -> impl Stream<Item = Result<TrainViewEvent>> {
+    let state = TrainViewStreamState {
+        client,
+        period: interval.max(MIN_POLL_INTERVAL),
+        interval: None,
+        snapshot: None,
+        pending: VecDeque::new(),
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                return Some((event, state));
+            }
+
+            let period = state.period;
+            state
+                .interval
+                .get_or_insert_with(|| {
+                    let mut interval = tokio::time::interval(period);
+                    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                    interval
+                })
+                .tick()
+                .await;
+
+            match state.client.train_view().await {
+                Ok(trains) => {
+                    let previous = state.snapshot.take().unwrap_or_default();
+                    state
+                        .pending
+                        .extend(TrainViewEvent::diff(&previous, &trains).into_iter().map(Ok));
+                    state.snapshot = Some(trains);
+                }
+                Err(err) => state.pending.push_back(Err(err)),
+            }
+        }
+    })
+}
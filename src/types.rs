@@ -1,25 +1,52 @@
 use std::{fmt, str::FromStr};
 
-use crate::errors::Error;
+use crate::{
+    deserialize::{deserialize_string_enum, deserialize_train_status},
+    errors::Error,
+    requests::Direction,
+};
+use chrono::Duration;
 use serde::{
     de::{self, Visitor},
-    Deserialize, Deserializer,
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
 };
-use strum::{Display, EnumCount, EnumIter, EnumString};
+use strum::{Display, EnumCount, EnumIter, EnumString, IntoEnumIterator};
+
+/// Serializes the enums as their display name and deserializes them from any of their names
+macro_rules! impl_string_enum_serde {
+    ($($enum_type:ty),*) => {
+        $(
+            impl Serialize for $enum_type {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $enum_type {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_string_enum(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_string_enum_serde!(
+    TransportType,
+    ServiceType,
+    RegionalRailsLine,
+    AlertRoute,
+    NhslStop,
+    MarketFrankfordStop,
+    BroadStreetStop,
+    LocationType,
+    BusRoute,
+    Track
+);
 
 #[derive(
-    Clone,
-    Debug,
-    Deserialize,
-    Display,
-    EnumString,
-    EnumCount,
-    EnumIter,
-    PartialEq,
-    Eq,
-    PartialOrd,
-    Ord,
-    Hash,
+    Clone, Debug, Display, EnumString, EnumCount, EnumIter, PartialEq, Eq, PartialOrd, Ord, Hash,
 )]
 pub enum TransportType {
     Bus,
@@ -30,18 +57,7 @@ pub enum TransportType {
 }
 
 #[derive(
-    Clone,
-    Debug,
-    Deserialize,
-    Display,
-    EnumString,
-    EnumCount,
-    EnumIter,
-    PartialEq,
-    Eq,
-    PartialOrd,
-    Ord,
-    Hash,
+    Clone, Debug, Display, EnumString, EnumCount, EnumIter, PartialEq, Eq, PartialOrd, Ord, Hash,
 )]
 #[strum(serialize_all = "UPPERCASE")]
 pub enum ServiceType {
@@ -52,19 +68,95 @@ pub enum ServiceType {
     Unknown(String),
 }
 
+/// The status SEPTA reports for a train, such as `"On Time"` or `"12 min"`
+///
+/// # Example
+///
+/// ```
+/// use chrono::Duration;
+/// use septa_api::types::TrainStatus;
+///
+/// assert_eq!(TrainStatus::from("12 min"), TrainStatus::Late(Duration::minutes(12)));
+/// assert_eq!(TrainStatus::from("On time"), TrainStatus::OnTime);
+/// assert_eq!(TrainStatus::from("Suspended").delay(), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TrainStatus {
+    OnTime,
+    Late(Duration),
+    Cancelled,
+    Suspended,
+    Other(String),
+}
+
+impl TrainStatus {
+    /// Returns how late the train is, `None` when the train is not running or the status is not
+    /// recognized
+    pub fn delay(&self) -> Option<Duration> {
+        match self {
+            Self::OnTime => Some(Duration::zero()),
+            Self::Late(delay) => Some(*delay),
+            Self::Cancelled | Self::Suspended | Self::Other(_) => None,
+        }
+    }
+}
+
+impl From<&str> for TrainStatus {
+    fn from(s: &str) -> Self {
+        let status = s.trim();
+
+        if status.eq_ignore_ascii_case("on time") {
+            return Self::OnTime;
+        }
+
+        if status.eq_ignore_ascii_case("cancelled") || status.eq_ignore_ascii_case("canceled") {
+            return Self::Cancelled;
+        }
+
+        if status.eq_ignore_ascii_case("suspended") {
+            return Self::Suspended;
+        }
+
+        let minutes = status
+            .split_once(' ')
+            .filter(|(_, unit)| {
+                unit.eq_ignore_ascii_case("min") || unit.eq_ignore_ascii_case("mins")
+            })
+            .and_then(|(minutes, _)| minutes.parse::<u32>().ok());
+
+        match minutes {
+            Some(minutes) => Self::Late(Duration::minutes(minutes.into())),
+            None => Self::Other(s.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for TrainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OnTime => write!(f, "On Time"),
+            Self::Late(delay) => write!(f, "{} min", delay.num_minutes()),
+            Self::Cancelled => write!(f, "Cancelled"),
+            Self::Suspended => write!(f, "Suspended"),
+            Self::Other(status) => write!(f, "{}", status),
+        }
+    }
+}
+
+impl Serialize for TrainStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TrainStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_train_status(deserializer)
+    }
+}
+
 #[derive(
-    Clone,
-    Debug,
-    Deserialize,
-    Display,
-    EnumString,
-    EnumCount,
-    EnumIter,
-    PartialEq,
-    Eq,
-    PartialOrd,
-    Ord,
-    Hash,
+    Clone, Debug, Display, EnumString, EnumCount, EnumIter, PartialEq, Eq, PartialOrd, Ord, Hash,
 )]
 #[strum(serialize_all = "title_case", ascii_case_insensitive)]
 pub enum RegionalRailsLine {
@@ -114,8 +206,539 @@ impl RegionalRailsLine {
         }
     }
 
+    /// Returns the stops served by the line, ordered outbound from Center City
+    ///
+    /// The list starts at the Center City station furthest from the line's branch and ends at the
+    /// outer terminus, so lines that leave Center City through Suburban Station towards 30th St
+    /// begin at Temple University while lines that leave through Jefferson Station begin at Gray
+    /// 30th St.
     pub fn stops(&self) -> Vec<RegionalRailStop> {
-        unimplemented!()
+        use RegionalRailStop::*;
+
+        match *self {
+            Self::Airport => vec![
+                TempleUniversity,
+                JeffersonStation,
+                SuburbanStation,
+                Gray30thStreet,
+                PennMedicineStation,
+                Eastwick,
+                AirportTerminalA,
+                AirportTerminalB,
+                AirportTerminalCD,
+                AirportTerminalEF,
+            ],
+            Self::ChestnutHillEast => vec![
+                Gray30thStreet,
+                SuburbanStation,
+                JeffersonStation,
+                TempleUniversity,
+                WayneJunction,
+                Wister,
+                Germantown,
+                WashingtonLane,
+                Stenton,
+                Sedgwick,
+                MountAiry,
+                Wyndmoor,
+                Gravers,
+                ChestnutHillEast,
+            ],
+            Self::ChestnutHillWest => vec![
+                TempleUniversity,
+                JeffersonStation,
+                SuburbanStation,
+                Gray30thStreet,
+                NorthPhiladelphiaSepta,
+                QueenLane,
+                CheltenAvenue,
+                Tulpehocken,
+                Upsal,
+                Carpenter,
+                RichardAllenLane,
+                StMartins,
+                Highland,
+                ChestnutHillWest,
+            ],
+            Self::Cynwyd => vec![
+                SuburbanStation,
+                Gray30thStreet,
+                WynnefieldAvenue,
+                Bala,
+                Cynwyd,
+            ],
+            Self::FoxChase => vec![
+                Gray30thStreet,
+                SuburbanStation,
+                JeffersonStation,
+                TempleUniversity,
+                WayneJunction,
+                Olney,
+                Lawndale,
+                Cheltenham,
+                Ryers,
+                FoxChase,
+            ],
+            Self::LansdaleDoylestown => vec![
+                Gray30thStreet,
+                SuburbanStation,
+                JeffersonStation,
+                TempleUniversity,
+                NorthBroad,
+                WayneJunction,
+                FernRockTC,
+                MelrosePark,
+                ElkinsPark,
+                JenkintownWyncote,
+                Glenside,
+                NorthHills,
+                Oreland,
+                FortWashington,
+                Ambler,
+                Penllyn,
+                GwyneddValley,
+                NorthWales,
+                Pennbrook,
+                Lansdale,
+                NinthStreetLansdale,
+                Fortuna,
+                Colmar,
+                LinkBelt,
+                Chalfont,
+                NewBritain,
+                DelawareValleyUniversity,
+                Doylestown,
+            ],
+            Self::MediaWawa => vec![
+                TempleUniversity,
+                JeffersonStation,
+                SuburbanStation,
+                Gray30thStreet,
+                PennMedicineStation,
+                FortyNinthStreet,
+                Angora,
+                FernwoodYeadon,
+                Lansdowne,
+                Gladstone,
+                CliftonAldan,
+                Primos,
+                Secane,
+                MortonRutledge,
+                Swarthmore,
+                Wallingford,
+                MoylanRoseValley,
+                Media,
+                Elwyn,
+                Wawa,
+            ],
+            Self::ManayunkNorristown => vec![
+                Gray30thStreet,
+                SuburbanStation,
+                JeffersonStation,
+                TempleUniversity,
+                NorthBroad,
+                Allegheny,
+                EastFalls,
+                Wissahickon,
+                Manayunk,
+                IvyRidge,
+                Miquon,
+                SpringMill,
+                Conshohocken,
+                NorristownTransitCenter,
+                MainStreet,
+                NorristownElmStreet,
+            ],
+            Self::PaoliThorndale => vec![
+                TempleUniversity,
+                JeffersonStation,
+                SuburbanStation,
+                Gray30thStreet,
+                Overbrook,
+                Merion,
+                Narberth,
+                Wynnewood,
+                Ardmore,
+                Haverford,
+                BrynMawr,
+                Rosemont,
+                Villanova,
+                Radnor,
+                StDavids,
+                Wayne,
+                Strafford,
+                Devon,
+                Berwyn,
+                Daylesford,
+                Paoli,
+                Malvern,
+                Exton,
+                Whitford,
+                Downingtown,
+                Thorndale,
+            ],
+            Self::Trenton => vec![
+                TempleUniversity,
+                JeffersonStation,
+                SuburbanStation,
+                Gray30thStreet,
+                NorthPhiladelphiaAmtrak,
+                Bridesburg,
+                Tacony,
+                HolmesburgJunction,
+                Torresdale,
+                CornwellsHeights,
+                Eddington,
+                Croydon,
+                Bristol,
+                Levittown,
+                Trenton,
+            ],
+            Self::Warminster => vec![
+                Gray30thStreet,
+                SuburbanStation,
+                JeffersonStation,
+                TempleUniversity,
+                WayneJunction,
+                FernRockTC,
+                MelrosePark,
+                ElkinsPark,
+                JenkintownWyncote,
+                Glenside,
+                Ardsley,
+                Roslyn,
+                Crestmont,
+                WillowGrove,
+                Hatboro,
+                Warminster,
+            ],
+            Self::WilmingtonNewark => vec![
+                TempleUniversity,
+                JeffersonStation,
+                SuburbanStation,
+                Gray30thStreet,
+                PennMedicineStation,
+                Darby,
+                CurtisPark,
+                SharonHill,
+                Folcroft,
+                Glenolden,
+                Norwood,
+                ProspectParkMoore,
+                RidleyPark,
+                CrumLynne,
+                Eddystone,
+                Chester,
+                HighlandAvenue,
+                MarcusHook,
+                Claymont,
+                Wilmington,
+                ChurchmansCrossing,
+                Newark,
+            ],
+            Self::WestTrenton => vec![
+                Gray30thStreet,
+                SuburbanStation,
+                JeffersonStation,
+                TempleUniversity,
+                WayneJunction,
+                FernRockTC,
+                MelrosePark,
+                ElkinsPark,
+                JenkintownWyncote,
+                Noble,
+                Rydal,
+                Meadowbrook,
+                Bethayres,
+                Philmont,
+                ForestHills,
+                Somerton,
+                Trevose,
+                NeshaminyFalls,
+                Langhorne,
+                Woodbourne,
+                Yardley,
+                WestTrenton,
+            ],
+        }
+    }
+
+    /// Returns the SEPTA direction a train travels in when heading outbound from Center City
+    ///
+    /// Lines on the old Reading side of the network leave Center City eastbound through Jefferson
+    /// Station and are therefore "Northbound", while lines on the old Pennsy side leave westbound
+    /// towards 30th St and are "Southbound".
+    pub fn outbound_direction(&self) -> Direction {
+        match *self {
+            Self::ChestnutHillEast
+            | Self::FoxChase
+            | Self::LansdaleDoylestown
+            | Self::ManayunkNorristown
+            | Self::Warminster
+            | Self::WestTrenton => Direction::North,
+            Self::Airport
+            | Self::ChestnutHillWest
+            | Self::Cynwyd
+            | Self::MediaWawa
+            | Self::PaoliThorndale
+            | Self::Trenton
+            | Self::WilmingtonNewark => Direction::South,
+        }
+    }
+
+    /// Returns the stops served by the line in the order a train travelling in `direction` visits
+    /// them
+    pub fn stops_in_direction(&self, direction: Direction) -> Vec<RegionalRailStop> {
+        let mut stops = self.stops();
+
+        if direction != self.outbound_direction() {
+            stops.reverse();
+        }
+
+        stops
+    }
+}
+
+/// Identifies a route of SEPTA's bus GTFS feed, such as `17`, `G1`, `BLVDDIR`, `10` or `LUCYGO`
+///
+/// Besides buses the feed has the trolleys, the subways and the NHSL. SEPTA refers to all of
+/// them by their route name, which is also the GTFS `route_id` of the route. Route names are
+/// case insensitive and are normalized to uppercase.
+///
+/// # Example
+///
+/// ```
+/// use septa_api::types::{BusRoute, TransportType};
+///
+/// let route = "lucygo".parse::<BusRoute>().unwrap();
+/// assert_eq!(route.route_id(), "LUCYGO");
+/// assert_eq!(route.transport_type(), TransportType::Bus);
+///
+/// let route = "10".parse::<BusRoute>().unwrap();
+/// assert_eq!(route.transport_type(), TransportType::Trolley);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BusRoute(String);
+
+impl BusRoute {
+    // Both the route names from before and after the SEPTA Metro renaming are listed
+    const TROLLEY_ROUTES: [&'static str; 16] = [
+        "10", "11", "13", "15", "34", "36", "101", "102", "T1", "T2", "T3", "T4", "T5", "D1", "D2",
+        "G1",
+    ];
+    const SUBWAY_ROUTES: [&'static str; 8] = ["MFL", "BSL", "MFO", "BSO", "L1", "B1", "B2", "B3"];
+    const NHSL_ROUTES: [&'static str; 3] = ["NHSL", "100", "M1"];
+
+    /// Returns the GTFS `route_id` of the route
+    pub fn route_id(&self) -> &str {
+        &self.0
+    }
+
+    pub fn transport_type(&self) -> TransportType {
+        let route_id = self.route_id();
+
+        if Self::TROLLEY_ROUTES.contains(&route_id) {
+            TransportType::Trolley
+        } else if Self::SUBWAY_ROUTES.contains(&route_id) {
+            TransportType::Subway
+        } else if Self::NHSL_ROUTES.contains(&route_id) {
+            TransportType::Nhsl
+        } else {
+            TransportType::Bus
+        }
+    }
+}
+
+impl fmt::Display for BusRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for BusRoute {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let route = s.trim();
+
+        if route.is_empty() || !route.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(Error::InvalidRoute(s.to_string()));
+        }
+
+        Ok(Self(route.to_ascii_uppercase()))
+    }
+}
+
+/// A track or platform a regional rail train stops at, such as `"2"` or `"1A"`
+///
+/// Tracks are never empty, SEPTA's empty strings are deserialized as `None`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Track(String);
+
+impl Track {
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Track {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Track {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let track = s.trim();
+
+        if track.is_empty() {
+            return Err(Error::InvalidTrack(s.to_string()));
+        }
+
+        Ok(Self(track.to_string()))
+    }
+}
+
+/// The track or platform a train was scheduled for and the one it was changed to, if any
+///
+/// # Example
+///
+/// ```
+/// use septa_api::types::{Track, TrackAssignment};
+///
+/// let assignment = TrackAssignment::new(Some("3".parse().unwrap()), Some("5".parse().unwrap()));
+///
+/// if let (Some(scheduled), Some(changed_to)) = (&assignment.scheduled, &assignment.changed_to) {
+///     println!("Track changed from {} to {}", scheduled, changed_to);
+/// }
+///
+/// assert!(assignment.is_changed());
+/// assert_eq!(assignment.current(), Some(&"5".parse::<Track>().unwrap()));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TrackAssignment {
+    pub scheduled: Option<Track>,
+
+    /// The new track, `None` when the track has not changed
+    pub changed_to: Option<Track>,
+}
+
+impl TrackAssignment {
+    /// Creates an assignment, a change to the scheduled track is not treated as a change
+    pub fn new(scheduled: Option<Track>, changed_to: Option<Track>) -> Self {
+        let changed_to = changed_to.filter(|changed_to| Some(changed_to) != scheduled.as_ref());
+
+        Self {
+            scheduled,
+            changed_to,
+        }
+    }
+
+    /// Returns the track the train will use, the changed track if there is one
+    pub fn current(&self) -> Option<&Track> {
+        self.changed_to.as_ref().or(self.scheduled.as_ref())
+    }
+
+    pub fn is_changed(&self) -> bool {
+        self.changed_to.is_some()
+    }
+}
+
+/// The kinds of locations that can be searched for with [`crate::Client::nearby_locations`]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Display,
+    EnumString,
+    EnumCount,
+    EnumIter,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum LocationType {
+    BusStops,
+    RailStations,
+    TrolleyStops,
+    SalesLocations,
+    PerkLocations,
+}
+
+/// Identifies the route an alert applies to
+///
+/// SEPTA keys alerts by a prefixed route id, `rr_route_` followed by the lowercase
+/// [`RegionalRailsLine::id`] for regional rail lines, `bus_route_` and `trolley_route_` followed by
+/// the route name for surface routes, and `generic` for system wide alerts.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AlertRoute {
+    Generic,
+    RegionalRail(RegionalRailsLine),
+    Bus(BusRoute),
+    Trolley(BusRoute),
+    Other(String),
+}
+
+impl AlertRoute {
+    const GENERIC: &'static str = "generic";
+    const REGIONAL_RAIL_PREFIX: &'static str = "rr_route_";
+    const BUS_PREFIX: &'static str = "bus_route_";
+    const TROLLEY_PREFIX: &'static str = "trolley_route_";
+}
+
+impl fmt::Display for AlertRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Generic => write!(f, "{}", Self::GENERIC),
+            Self::RegionalRail(line) => write!(
+                f,
+                "{}{}",
+                Self::REGIONAL_RAIL_PREFIX,
+                line.id().to_ascii_lowercase()
+            ),
+            Self::Bus(route) => write!(f, "{}{}", Self::BUS_PREFIX, route),
+            Self::Trolley(route) => write!(f, "{}{}", Self::TROLLEY_PREFIX, route),
+            Self::Other(route) => write!(f, "{}", route),
+        }
+    }
+}
+
+impl FromStr for AlertRoute {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case(Self::GENERIC) {
+            return Ok(Self::Generic);
+        }
+
+        if let Some(line_id) = s.strip_prefix(Self::REGIONAL_RAIL_PREFIX) {
+            if let Some(line) =
+                RegionalRailsLine::iter().find(|line| line.id().eq_ignore_ascii_case(line_id))
+            {
+                return Ok(Self::RegionalRail(line));
+            }
+        } else if let Some(route) = s.strip_prefix(Self::BUS_PREFIX) {
+            if let Ok(route) = route.parse() {
+                return Ok(Self::Bus(route));
+            }
+        } else if let Some(route) = s.strip_prefix(Self::TROLLEY_PREFIX) {
+            if let Ok(route) = route.parse() {
+                return Ok(Self::Trolley(route));
+            }
+        }
+
+        Ok(Self::Other(s.to_string()))
+    }
+}
+
+impl From<RegionalRailsLine> for AlertRoute {
+    fn from(line: RegionalRailsLine) -> Self {
+        Self::RegionalRail(line)
     }
 }
 
@@ -425,349 +1048,590 @@ impl<'de> Deserialize<'de> for RegionalRailStop {
     }
 }
 
+/// Serializes the stop as `{"name": "Suburban Station", "stop_id": 90005}`
+///
+/// The `stop_id` is `null` for [`RegionalRailStop::Unknown`] stops.
+impl Serialize for RegionalRailStop {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("RegionalRailStop", 2)?;
+        state.serialize_field("name", &self.to_string())?;
+        state.serialize_field("stop_id", &self.stop_id().ok())?;
+        state.end()
+    }
+}
+
 impl RegionalRailStop {
+    /// Returns the stop as a [`KnownRegionalRailStop`], or an error for
+    /// [`RegionalRailStop::Unknown`] stops
+    pub fn known(&self) -> Result<KnownRegionalRailStop, Error> {
+        KnownRegionalRailStop::try_from(self.clone())
+    }
+
+    /// Returns the GTFS stop id, only [`RegionalRailStop::Unknown`] stops return an error
+    ///
+    /// Use [`KnownRegionalRailStop::stop_id`] when the stop is known to not be unknown.
     pub fn stop_id(&self) -> Result<u32, Error> {
-        match *self {
-            Self::Cynwyd => Ok(90001),
-            Self::Bala => Ok(90002),
-            Self::WynnefieldAvenue => Ok(90003),
-            Self::Gray30thStreet => Ok(90004),
-            Self::SuburbanStation => Ok(90005),
-            Self::JeffersonStation => Ok(90006),
-            Self::TempleUniversity => Ok(90007),
-            Self::NorthBroad => Ok(90008),
-            Self::WayneJunction => Ok(90009),
-            Self::Newark => Ok(90201),
-            Self::ChurchmansCrossing => Ok(90202),
-            Self::Wilmington => Ok(90203),
-            Self::Claymont => Ok(90204),
-            Self::MarcusHook => Ok(90205),
-            Self::HighlandAvenue => Ok(90206),
-            Self::Chester => Ok(90207),
-            Self::Eddystone => Ok(90208),
-            Self::CrumLynne => Ok(90209),
-            Self::RidleyPark => Ok(90210),
-            Self::ProspectParkMoore => Ok(90211),
-            Self::Norwood => Ok(90212),
-            Self::Glenolden => Ok(90213),
-            Self::Folcroft => Ok(90214),
-            Self::SharonHill => Ok(90215),
-            Self::CurtisPark => Ok(90216),
-            Self::Darby => Ok(90217),
-            Self::Allegheny => Ok(90218),
-            Self::EastFalls => Ok(90219),
-            Self::Wissahickon => Ok(90220),
-            Self::Manayunk => Ok(90221),
-            Self::IvyRidge => Ok(90222),
-            Self::Miquon => Ok(90223),
-            Self::SpringMill => Ok(90224),
-            Self::Conshohocken => Ok(90225),
-            Self::NorristownTransitCenter => Ok(90226),
-            Self::MainStreet => Ok(90227),
-            Self::NorristownElmStreet => Ok(90228),
-            Self::Wawa => Ok(90300),
-            Self::Elwyn => Ok(90301),
-            Self::Media => Ok(90302),
-            Self::MoylanRoseValley => Ok(90303),
-            Self::Wallingford => Ok(90304),
-            Self::Swarthmore => Ok(90305),
-            Self::MortonRutledge => Ok(90306),
-            Self::Secane => Ok(90307),
-            Self::Primos => Ok(90308),
-            Self::CliftonAldan => Ok(90309),
-            Self::Gladstone => Ok(90310),
-            Self::Lansdowne => Ok(90311),
-            Self::FernwoodYeadon => Ok(90312),
-            Self::Angora => Ok(90313),
-            Self::FortyNinthStreet => Ok(90314),
-            Self::Noble => Ok(90315),
-            Self::Rydal => Ok(90316),
-            Self::Meadowbrook => Ok(90317),
-            Self::Bethayres => Ok(90318),
-            Self::Philmont => Ok(90319),
-            Self::ForestHills => Ok(90320),
-            Self::Somerton => Ok(90321),
-            Self::Trevose => Ok(90322),
-            Self::NeshaminyFalls => Ok(90323),
-            Self::Langhorne => Ok(90324),
-            Self::Woodbourne => Ok(90325),
-            Self::Yardley => Ok(90326),
-            Self::WestTrenton => Ok(90327),
-            Self::AirportTerminalEF => Ok(90401),
-            Self::AirportTerminalCD => Ok(90402),
-            Self::AirportTerminalB => Ok(90403),
-            Self::AirportTerminalA => Ok(90404),
-            Self::Eastwick => Ok(90405),
-            Self::PennMedicineStation => Ok(90406),
-            Self::FernRockTC => Ok(90407),
-            Self::MelrosePark => Ok(90408),
-            Self::ElkinsPark => Ok(90409),
-            Self::JenkintownWyncote => Ok(90410),
-            Self::Glenside => Ok(90411),
-            Self::Ardsley => Ok(90412),
-            Self::Roslyn => Ok(90413),
-            Self::Crestmont => Ok(90414),
-            Self::WillowGrove => Ok(90415),
-            Self::Hatboro => Ok(90416),
-            Self::Warminster => Ok(90417),
-            Self::Thorndale => Ok(90501),
-            Self::Downingtown => Ok(90502),
-            Self::Whitford => Ok(90503),
-            Self::Exton => Ok(90504),
-            Self::Malvern => Ok(90505),
-            Self::Paoli => Ok(90506),
-            Self::Daylesford => Ok(90507),
-            Self::Berwyn => Ok(90508),
-            Self::Devon => Ok(90509),
-            Self::Strafford => Ok(90510),
-            Self::Wayne => Ok(90511),
-            Self::StDavids => Ok(90512),
-            Self::Radnor => Ok(90513),
-            Self::Villanova => Ok(90514),
-            Self::Rosemont => Ok(90515),
-            Self::BrynMawr => Ok(90516),
-            Self::Haverford => Ok(90517),
-            Self::Ardmore => Ok(90518),
-            Self::Wynnewood => Ok(90519),
-            Self::Narberth => Ok(90520),
-            Self::Merion => Ok(90521),
-            Self::Overbrook => Ok(90522),
-            Self::NorthHills => Ok(90523),
-            Self::Oreland => Ok(90524),
-            Self::FortWashington => Ok(90525),
-            Self::Ambler => Ok(90526),
-            Self::Penllyn => Ok(90527),
-            Self::GwyneddValley => Ok(90528),
-            Self::NorthWales => Ok(90529),
-            Self::Pennbrook => Ok(90530),
-            Self::Lansdale => Ok(90531),
-            Self::Fortuna => Ok(90532),
-            Self::Colmar => Ok(90533),
-            Self::LinkBelt => Ok(90534),
-            Self::Chalfont => Ok(90535),
-            Self::NewBritain => Ok(90536),
-            Self::DelawareValleyUniversity => Ok(90537),
-            Self::Doylestown => Ok(90538),
-            Self::NinthStreetLansdale => Ok(90539),
-            Self::Trenton => Ok(90701),
-            Self::Levittown => Ok(90702),
-            Self::Bristol => Ok(90703),
-            Self::Croydon => Ok(90704),
-            Self::Eddington => Ok(90705),
-            Self::CornwellsHeights => Ok(90706),
-            Self::Torresdale => Ok(90707),
-            Self::HolmesburgJunction => Ok(90708),
-            Self::Tacony => Ok(90709),
-            Self::Bridesburg => Ok(90710),
-            Self::NorthPhiladelphiaAmtrak => Ok(90711),
-            Self::Wister => Ok(90712),
-            Self::Germantown => Ok(90713),
-            Self::WashingtonLane => Ok(90714),
-            Self::Stenton => Ok(90715),
-            Self::Sedgwick => Ok(90716),
-            Self::MountAiry => Ok(90717),
-            Self::Wyndmoor => Ok(90718),
-            Self::Gravers => Ok(90719),
-            Self::ChestnutHillEast => Ok(90720),
-            Self::ChestnutHillWest => Ok(90801),
-            Self::Highland => Ok(90802),
-            Self::StMartins => Ok(90803),
-            Self::RichardAllenLane => Ok(90804),
-            Self::Carpenter => Ok(90805),
-            Self::Upsal => Ok(90806),
-            Self::Tulpehocken => Ok(90807),
-            Self::CheltenAvenue => Ok(90808),
-            Self::QueenLane => Ok(90809),
-            Self::NorthPhiladelphiaSepta => Ok(90810),
-            Self::Olney => Ok(90811),
-            Self::Lawndale => Ok(90812),
-            Self::Cheltenham => Ok(90813),
-            Self::Ryers => Ok(90814),
-            Self::FoxChase => Ok(90815),
-            Self::Unknown(ref station) => {
-                Err(Error::UnknownRegionalRailStation(station.to_string()))
+        Ok(self.known()?.stop_id())
+    }
+
+    /// Returns the `(lat, lon)` of the stop, only [`RegionalRailStop::Unknown`] stops return an
+    /// error
+    ///
+    /// Use [`KnownRegionalRailStop::lat_lon`] when the stop is known to not be unknown.
+    pub fn lat_lon(&self) -> Result<(f64, f64), Error> {
+        Ok(self.known()?.lat_lon())
+    }
+}
+
+/// Generates [`KnownRegionalRailStop`] with the given variants of [`RegionalRailStop`] and the
+/// conversions between the two
+macro_rules! known_regional_rail_stops {
+    ($($stop:ident),* $(,)?) => {
+        /// A [`RegionalRailStop`] that is not [`RegionalRailStop::Unknown`]
+        ///
+        /// Every known stop has a GTFS stop id and coordinates, so unlike the methods on
+        /// [`RegionalRailStop`] the lookups can not fail.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use septa_api::types::{KnownRegionalRailStop, RegionalRailStop};
+        ///
+        /// let stop = RegionalRailStop::SuburbanStation.known().unwrap();
+        /// assert_eq!(stop, KnownRegionalRailStop::SuburbanStation);
+        /// assert_eq!(stop.stop_id(), 90005);
+        ///
+        /// let (lat, lon) = stop.lat_lon();
+        /// println!("{} is at {}, {}", stop, lat, lon);
+        ///
+        /// assert!(RegionalRailStop::Unknown("Nowhere".to_string()).known().is_err());
+        /// ```
+        #[derive(
+            Clone, Copy, Debug, EnumCount, EnumIter, PartialEq, Eq, PartialOrd, Ord, Hash,
+        )]
+        pub enum KnownRegionalRailStop {
+            $($stop),*
+        }
+
+        impl TryFrom<RegionalRailStop> for KnownRegionalRailStop {
+            type Error = Error;
+
+            fn try_from(stop: RegionalRailStop) -> Result<Self, Self::Error> {
+                match stop {
+                    $(RegionalRailStop::$stop => Ok(Self::$stop),)*
+                    RegionalRailStop::Unknown(station) => {
+                        Err(Error::UnknownRegionalRailStation(station))
+                    }
+                }
             }
         }
+
+        impl From<KnownRegionalRailStop> for RegionalRailStop {
+            fn from(stop: KnownRegionalRailStop) -> Self {
+                match stop {
+                    $(KnownRegionalRailStop::$stop => Self::$stop),*
+                }
+            }
+        }
+    };
+}
+
+known_regional_rail_stops!(
+    AirportTerminalEF,
+    AirportTerminalCD,
+    AirportTerminalB,
+    AirportTerminalA,
+    Eastwick,
+    Stenton,
+    Wyndmoor,
+    Wister,
+    Gravers,
+    Germantown,
+    Sedgwick,
+    ChestnutHillEast,
+    WashingtonLane,
+    MountAiry,
+    NorthPhiladelphiaSepta,
+    Upsal,
+    StMartins,
+    ChestnutHillWest,
+    CheltenAvenue,
+    Carpenter,
+    RichardAllenLane,
+    Tulpehocken,
+    Highland,
+    QueenLane,
+    Cynwyd,
+    Bala,
+    WynnefieldAvenue,
+    FoxChase,
+    Ryers,
+    Cheltenham,
+    Lawndale,
+    Olney,
+    Doylestown,
+    DelawareValleyUniversity,
+    NewBritain,
+    Chalfont,
+    LinkBelt,
+    Colmar,
+    Fortuna,
+    NinthStreetLansdale,
+    Lansdale,
+    Pennbrook,
+    NorthWales,
+    GwyneddValley,
+    Penllyn,
+    Ambler,
+    FortWashington,
+    Oreland,
+    NorthHills,
+    NorthBroad,
+    Wawa,
+    Elwyn,
+    Media,
+    MoylanRoseValley,
+    Wallingford,
+    Swarthmore,
+    MortonRutledge,
+    Secane,
+    Primos,
+    CliftonAldan,
+    Gladstone,
+    Lansdowne,
+    FernwoodYeadon,
+    Angora,
+    FortyNinthStreet,
+    NorristownElmStreet,
+    MainStreet,
+    NorristownTransitCenter,
+    Conshohocken,
+    SpringMill,
+    Miquon,
+    IvyRidge,
+    Manayunk,
+    Wissahickon,
+    EastFalls,
+    Allegheny,
+    Thorndale,
+    Downingtown,
+    Whitford,
+    Exton,
+    Malvern,
+    Paoli,
+    Wayne,
+    StDavids,
+    Berwyn,
+    Devon,
+    Villanova,
+    Rosemont,
+    BrynMawr,
+    Strafford,
+    Daylesford,
+    Radnor,
+    Haverford,
+    Ardmore,
+    Wynnewood,
+    Narberth,
+    Overbrook,
+    Merion,
+    Trenton,
+    Levittown,
+    Bristol,
+    Croydon,
+    Eddington,
+    CornwellsHeights,
+    Torresdale,
+    HolmesburgJunction,
+    Tacony,
+    Bridesburg,
+    NorthPhiladelphiaAmtrak,
+    Warminster,
+    Hatboro,
+    WillowGrove,
+    Crestmont,
+    Roslyn,
+    Ardsley,
+    Newark,
+    ChurchmansCrossing,
+    Wilmington,
+    Claymont,
+    MarcusHook,
+    HighlandAvenue,
+    Chester,
+    Eddystone,
+    CrumLynne,
+    RidleyPark,
+    ProspectParkMoore,
+    Norwood,
+    Glenolden,
+    Folcroft,
+    SharonHill,
+    CurtisPark,
+    Darby,
+    WestTrenton,
+    Yardley,
+    Woodbourne,
+    Langhorne,
+    NeshaminyFalls,
+    Trevose,
+    Somerton,
+    ForestHills,
+    Philmont,
+    Bethayres,
+    Meadowbrook,
+    Rydal,
+    Noble,
+    WayneJunction,
+    Glenside,
+    JenkintownWyncote,
+    FernRockTC,
+    ElkinsPark,
+    MelrosePark,
+    Gray30thStreet,
+    SuburbanStation,
+    JeffersonStation,
+    TempleUniversity,
+    PennMedicineStation,
+);
+
+impl KnownRegionalRailStop {
+    /// Returns the GTFS stop id of the stop
+    pub fn stop_id(&self) -> u32 {
+        match *self {
+            Self::Cynwyd => 90001,
+            Self::Bala => 90002,
+            Self::WynnefieldAvenue => 90003,
+            Self::Gray30thStreet => 90004,
+            Self::SuburbanStation => 90005,
+            Self::JeffersonStation => 90006,
+            Self::TempleUniversity => 90007,
+            Self::NorthBroad => 90008,
+            Self::WayneJunction => 90009,
+            Self::Newark => 90201,
+            Self::ChurchmansCrossing => 90202,
+            Self::Wilmington => 90203,
+            Self::Claymont => 90204,
+            Self::MarcusHook => 90205,
+            Self::HighlandAvenue => 90206,
+            Self::Chester => 90207,
+            Self::Eddystone => 90208,
+            Self::CrumLynne => 90209,
+            Self::RidleyPark => 90210,
+            Self::ProspectParkMoore => 90211,
+            Self::Norwood => 90212,
+            Self::Glenolden => 90213,
+            Self::Folcroft => 90214,
+            Self::SharonHill => 90215,
+            Self::CurtisPark => 90216,
+            Self::Darby => 90217,
+            Self::Allegheny => 90218,
+            Self::EastFalls => 90219,
+            Self::Wissahickon => 90220,
+            Self::Manayunk => 90221,
+            Self::IvyRidge => 90222,
+            Self::Miquon => 90223,
+            Self::SpringMill => 90224,
+            Self::Conshohocken => 90225,
+            Self::NorristownTransitCenter => 90226,
+            Self::MainStreet => 90227,
+            Self::NorristownElmStreet => 90228,
+            Self::Wawa => 90300,
+            Self::Elwyn => 90301,
+            Self::Media => 90302,
+            Self::MoylanRoseValley => 90303,
+            Self::Wallingford => 90304,
+            Self::Swarthmore => 90305,
+            Self::MortonRutledge => 90306,
+            Self::Secane => 90307,
+            Self::Primos => 90308,
+            Self::CliftonAldan => 90309,
+            Self::Gladstone => 90310,
+            Self::Lansdowne => 90311,
+            Self::FernwoodYeadon => 90312,
+            Self::Angora => 90313,
+            Self::FortyNinthStreet => 90314,
+            Self::Noble => 90315,
+            Self::Rydal => 90316,
+            Self::Meadowbrook => 90317,
+            Self::Bethayres => 90318,
+            Self::Philmont => 90319,
+            Self::ForestHills => 90320,
+            Self::Somerton => 90321,
+            Self::Trevose => 90322,
+            Self::NeshaminyFalls => 90323,
+            Self::Langhorne => 90324,
+            Self::Woodbourne => 90325,
+            Self::Yardley => 90326,
+            Self::WestTrenton => 90327,
+            Self::AirportTerminalEF => 90401,
+            Self::AirportTerminalCD => 90402,
+            Self::AirportTerminalB => 90403,
+            Self::AirportTerminalA => 90404,
+            Self::Eastwick => 90405,
+            Self::PennMedicineStation => 90406,
+            Self::FernRockTC => 90407,
+            Self::MelrosePark => 90408,
+            Self::ElkinsPark => 90409,
+            Self::JenkintownWyncote => 90410,
+            Self::Glenside => 90411,
+            Self::Ardsley => 90412,
+            Self::Roslyn => 90413,
+            Self::Crestmont => 90414,
+            Self::WillowGrove => 90415,
+            Self::Hatboro => 90416,
+            Self::Warminster => 90417,
+            Self::Thorndale => 90501,
+            Self::Downingtown => 90502,
+            Self::Whitford => 90503,
+            Self::Exton => 90504,
+            Self::Malvern => 90505,
+            Self::Paoli => 90506,
+            Self::Daylesford => 90507,
+            Self::Berwyn => 90508,
+            Self::Devon => 90509,
+            Self::Strafford => 90510,
+            Self::Wayne => 90511,
+            Self::StDavids => 90512,
+            Self::Radnor => 90513,
+            Self::Villanova => 90514,
+            Self::Rosemont => 90515,
+            Self::BrynMawr => 90516,
+            Self::Haverford => 90517,
+            Self::Ardmore => 90518,
+            Self::Wynnewood => 90519,
+            Self::Narberth => 90520,
+            Self::Merion => 90521,
+            Self::Overbrook => 90522,
+            Self::NorthHills => 90523,
+            Self::Oreland => 90524,
+            Self::FortWashington => 90525,
+            Self::Ambler => 90526,
+            Self::Penllyn => 90527,
+            Self::GwyneddValley => 90528,
+            Self::NorthWales => 90529,
+            Self::Pennbrook => 90530,
+            Self::Lansdale => 90531,
+            Self::Fortuna => 90532,
+            Self::Colmar => 90533,
+            Self::LinkBelt => 90534,
+            Self::Chalfont => 90535,
+            Self::NewBritain => 90536,
+            Self::DelawareValleyUniversity => 90537,
+            Self::Doylestown => 90538,
+            Self::NinthStreetLansdale => 90539,
+            Self::Trenton => 90701,
+            Self::Levittown => 90702,
+            Self::Bristol => 90703,
+            Self::Croydon => 90704,
+            Self::Eddington => 90705,
+            Self::CornwellsHeights => 90706,
+            Self::Torresdale => 90707,
+            Self::HolmesburgJunction => 90708,
+            Self::Tacony => 90709,
+            Self::Bridesburg => 90710,
+            Self::NorthPhiladelphiaAmtrak => 90711,
+            Self::Wister => 90712,
+            Self::Germantown => 90713,
+            Self::WashingtonLane => 90714,
+            Self::Stenton => 90715,
+            Self::Sedgwick => 90716,
+            Self::MountAiry => 90717,
+            Self::Wyndmoor => 90718,
+            Self::Gravers => 90719,
+            Self::ChestnutHillEast => 90720,
+            Self::ChestnutHillWest => 90801,
+            Self::Highland => 90802,
+            Self::StMartins => 90803,
+            Self::RichardAllenLane => 90804,
+            Self::Carpenter => 90805,
+            Self::Upsal => 90806,
+            Self::Tulpehocken => 90807,
+            Self::CheltenAvenue => 90808,
+            Self::QueenLane => 90809,
+            Self::NorthPhiladelphiaSepta => 90810,
+            Self::Olney => 90811,
+            Self::Lawndale => 90812,
+            Self::Cheltenham => 90813,
+            Self::Ryers => 90814,
+            Self::FoxChase => 90815,
+        }
     }
 
-    pub fn lat_lon(&self) -> Result<(f64, f64), Error> {
+    /// Returns the `(lat, lon)` of the stop
+    pub fn lat_lon(&self) -> (f64, f64) {
         match *self {
-            Self::Cynwyd => Ok((40.006670, -75.231670)),
-            Self::Bala => Ok((40.001110, -75.227780)),
-            Self::WynnefieldAvenue => Ok((39.990000, -75.225560)),
-            Self::Gray30thStreet => Ok((39.956670, -75.181660)),
-            Self::SuburbanStation => Ok((39.953890, -75.167780)),
-            Self::JeffersonStation => Ok((39.952500, -75.158060)),
-            Self::TempleUniversity => Ok((39.981390, -75.149440)),
-            Self::NorthBroad => Ok((39.992220, -75.153890)),
-            Self::WayneJunction => Ok((40.022220, -75.160000)),
-            Self::Newark => Ok((39.669690, -75.753510)),
-            Self::ChurchmansCrossing => Ok((39.695000, -75.672500)),
-            Self::Wilmington => Ok((39.737260, -75.551090)),
-            Self::Claymont => Ok((39.797780, -75.452220)),
-            Self::MarcusHook => Ok((39.821670, -75.419440)),
-            Self::HighlandAvenue => Ok((39.833610, -75.393330)),
-            Self::Chester => Ok((39.849720, -75.360000)),
-            Self::Eddystone => Ok((39.857220, -75.342220)),
-            Self::CrumLynne => Ok((39.871940, -75.331110)),
-            Self::RidleyPark => Ok((39.880550, -75.322220)),
-            Self::ProspectParkMoore => Ok((39.888330, -75.308890)),
-            Self::Norwood => Ok((39.891670, -75.301670)),
-            Self::Glenolden => Ok((39.896390, -75.290000)),
-            Self::Folcroft => Ok((39.900550, -75.279720)),
-            Self::SharonHill => Ok((39.904450, -75.270840)),
-            Self::CurtisPark => Ok((39.908050, -75.265000)),
-            Self::Darby => Ok((39.913060, -75.254450)),
-            Self::Allegheny => Ok((40.003610, -75.164720)),
-            Self::EastFalls => Ok((40.011390, -75.191950)),
-            Self::Wissahickon => Ok((40.016670, -75.210280)),
-            Self::Manayunk => Ok((40.026940, -75.225000)),
-            Self::IvyRidge => Ok((40.034170, -75.235560)),
-            Self::Miquon => Ok((40.058610, -75.266390)),
-            Self::SpringMill => Ok((40.074170, -75.286110)),
-            Self::Conshohocken => Ok((40.072220, -75.308610)),
-            Self::NorristownTransitCenter => Ok((40.112780, -75.344170)),
-            Self::MainStreet => Ok((40.117220, -75.348610)),
-            Self::NorristownElmStreet => Ok((40.120830, -75.345000)),
-            Self::Wawa => Ok((39.900680, -75.458560)),
-            Self::Elwyn => Ok((39.907500, -75.411670)),
-            Self::Media => Ok((39.914440, -75.395000)),
-            Self::MoylanRoseValley => Ok((39.906110, -75.388610)),
-            Self::Wallingford => Ok((39.903610, -75.371940)),
-            Self::Swarthmore => Ok((39.902220, -75.350830)),
-            Self::MortonRutledge => Ok((39.907780, -75.328890)),
-            Self::Secane => Ok((39.915830, -75.309720)),
-            Self::Primos => Ok((39.921670, -75.298330)),
-            Self::CliftonAldan => Ok((39.926670, -75.290280)),
-            Self::Gladstone => Ok((39.932780, -75.282220)),
-            Self::Lansdowne => Ok((39.937500, -75.270840)),
-            Self::FernwoodYeadon => Ok((39.939720, -75.255840)),
-            Self::Angora => Ok((39.944720, -75.238610)),
-            Self::FortyNinthStreet => Ok((39.943610, -75.216670)),
-            Self::Noble => Ok((40.104440, -75.124170)),
-            Self::Rydal => Ok((40.107500, -75.110560)),
-            Self::Meadowbrook => Ok((40.111390, -75.092500)),
-            Self::Bethayres => Ok((40.116660, -75.068340)),
-            Self::Philmont => Ok((40.121940, -75.043610)),
-            Self::ForestHills => Ok((40.127780, -75.020550)),
-            Self::Somerton => Ok((40.130550, -75.011950)),
-            Self::Trevose => Ok((40.140280, -74.982500)),
-            Self::NeshaminyFalls => Ok((40.146950, -74.961670)),
-            Self::Langhorne => Ok((40.160830, -74.912500)),
-            Self::Woodbourne => Ok((40.192500, -74.889170)),
-            Self::Yardley => Ok((40.235280, -74.830560)),
-            Self::WestTrenton => Ok((40.257780, -74.815280)),
-            Self::AirportTerminalEF => Ok((39.879440, -75.239720)),
-            Self::AirportTerminalCD => Ok((39.878060, -75.240000)),
-            Self::AirportTerminalB => Ok((39.877220, -75.241390)),
-            Self::AirportTerminalA => Ok((39.876110, -75.245280)),
-            Self::Eastwick => Ok((39.892780, -75.243890)),
-            Self::PennMedicineStation => Ok((39.948060, -75.190280)),
-            Self::FernRockTC => Ok((40.040550, -75.134720)),
-            Self::MelrosePark => Ok((40.059440, -75.129170)),
-            Self::ElkinsPark => Ok((40.071390, -75.127780)),
-            Self::JenkintownWyncote => Ok((40.092780, -75.137500)),
-            Self::Glenside => Ok((40.101390, -75.153610)),
-            Self::Ardsley => Ok((40.114170, -75.153050)),
-            Self::Roslyn => Ok((40.120830, -75.134160)),
-            Self::Crestmont => Ok((40.133340, -75.118610)),
-            Self::WillowGrove => Ok((40.143890, -75.114170)),
-            Self::Hatboro => Ok((40.176110, -75.102500)),
-            Self::Warminster => Ok((40.195280, -75.089160)),
-            Self::Thorndale => Ok((39.992780, -75.763610)),
-            Self::Downingtown => Ok((40.002190, -75.710780)),
-            Self::Whitford => Ok((40.014720, -75.638050)),
-            Self::Exton => Ok((40.019290, -75.621710)),
-            Self::Malvern => Ok((40.036390, -75.515560)),
-            Self::Paoli => Ok((40.042760, -75.483760)),
-            Self::Daylesford => Ok((40.043060, -75.460560)),
-            Self::Berwyn => Ok((40.048050, -75.442220)),
-            Self::Devon => Ok((40.047220, -75.422780)),
-            Self::Strafford => Ok((40.049450, -75.403050)),
-            Self::Wayne => Ok((40.045830, -75.386670)),
-            Self::StDavids => Ok((40.043890, -75.372500)),
-            Self::Radnor => Ok((40.044720, -75.358890)),
-            Self::Villanova => Ok((40.038330, -75.341670)),
-            Self::Rosemont => Ok((40.027780, -75.326670)),
-            Self::BrynMawr => Ok((40.021950, -75.316390)),
-            Self::Haverford => Ok((40.013890, -75.299720)),
-            Self::Ardmore => Ok((40.008280, -75.290400)),
-            Self::Wynnewood => Ok((40.002780, -75.272500)),
-            Self::Narberth => Ok((40.004720, -75.261390)),
-            Self::Merion => Ok((39.998610, -75.251390)),
-            Self::Overbrook => Ok((39.989440, -75.249440)),
-            Self::NorthHills => Ok((40.111950, -75.169440)),
-            Self::Oreland => Ok((40.118330, -75.183890)),
-            Self::FortWashington => Ok((40.135830, -75.212220)),
-            Self::Ambler => Ok((40.153610, -75.224720)),
-            Self::Penllyn => Ok((40.170000, -75.244160)),
-            Self::GwyneddValley => Ok((40.184720, -75.256940)),
-            Self::NorthWales => Ok((40.214170, -75.277220)),
-            Self::Pennbrook => Ok((40.230280, -75.281670)),
-            Self::Lansdale => Ok((40.242780, -75.285000)),
-            Self::Fortuna => Ok((40.259450, -75.266110)),
-            Self::Colmar => Ok((40.268330, -75.254450)),
-            Self::LinkBelt => Ok((40.273890, -75.246670)),
-            Self::Chalfont => Ok((40.287780, -75.209720)),
-            Self::NewBritain => Ok((40.297500, -75.179730)),
-            Self::DelawareValleyUniversity => Ok((40.297220, -75.161670)),
-            Self::Doylestown => Ok((40.306390, -75.130280)),
-            Self::NinthStreetLansdale => Ok((40.250000, -75.279170)),
-            Self::Trenton => Ok((40.218510, -74.753930)),
-            Self::Levittown => Ok((40.140280, -74.816950)),
-            Self::Bristol => Ok((40.104720, -74.854720)),
-            Self::Croydon => Ok((40.093610, -74.906670)),
-            Self::Eddington => Ok((40.083060, -74.933610)),
-            Self::CornwellsHeights => Ok((40.070900, -74.954320)),
-            Self::Torresdale => Ok((40.054440, -74.984440)),
-            Self::HolmesburgJunction => Ok((40.032780, -75.023610)),
-            Self::Tacony => Ok((40.023330, -75.038890)),
-            Self::Bridesburg => Ok((40.010560, -75.069730)),
-            Self::NorthPhiladelphiaAmtrak => Ok((39.996780, -75.155110)),
-            Self::Wister => Ok((40.036110, -75.161110)),
-            Self::Germantown => Ok((40.037500, -75.171670)),
-            Self::WashingtonLane => Ok((40.050830, -75.171390)),
-            Self::Stenton => Ok((40.060550, -75.178610)),
-            Self::Sedgwick => Ok((40.062780, -75.185280)),
-            Self::MountAiry => Ok((40.065280, -75.190830)),
-            Self::Wyndmoor => Ok((40.073330, -75.196660)),
-            Self::Gravers => Ok((40.077500, -75.201670)),
-            Self::ChestnutHillEast => Ok((40.081110, -75.207220)),
-            Self::ChestnutHillWest => Ok((40.076390, -75.208340)),
-            Self::Highland => Ok((40.070560, -75.211110)),
-            Self::StMartins => Ok((40.065830, -75.204440)),
-            Self::RichardAllenLane => Ok((40.057500, -75.194730)),
-            Self::Carpenter => Ok((40.051110, -75.191390)),
-            Self::Upsal => Ok((40.042500, -75.190000)),
-            Self::Tulpehocken => Ok((40.035280, -75.186940)),
-            Self::CheltenAvenue => Ok((40.030000, -75.180830)),
-            Self::QueenLane => Ok((40.023330, -75.178050)),
-            Self::NorthPhiladelphiaSepta => Ok((39.997780, -75.156390)),
-            Self::Olney => Ok((40.033330, -75.122780)),
-            Self::Lawndale => Ok((40.051390, -75.103060)),
-            Self::Cheltenham => Ok((40.058060, -75.092780)),
-            Self::Ryers => Ok((40.064170, -75.086390)),
-            Self::FoxChase => Ok((40.076390, -75.083340)),
-            Self::Unknown(ref station) => {
-                Err(Error::UnknownRegionalRailStation(station.to_string()))
-            }
+            Self::Cynwyd => (40.006670, -75.231670),
+            Self::Bala => (40.001110, -75.227780),
+            Self::WynnefieldAvenue => (39.990000, -75.225560),
+            Self::Gray30thStreet => (39.956670, -75.181660),
+            Self::SuburbanStation => (39.953890, -75.167780),
+            Self::JeffersonStation => (39.952500, -75.158060),
+            Self::TempleUniversity => (39.981390, -75.149440),
+            Self::NorthBroad => (39.992220, -75.153890),
+            Self::WayneJunction => (40.022220, -75.160000),
+            Self::Newark => (39.669690, -75.753510),
+            Self::ChurchmansCrossing => (39.695000, -75.672500),
+            Self::Wilmington => (39.737260, -75.551090),
+            Self::Claymont => (39.797780, -75.452220),
+            Self::MarcusHook => (39.821670, -75.419440),
+            Self::HighlandAvenue => (39.833610, -75.393330),
+            Self::Chester => (39.849720, -75.360000),
+            Self::Eddystone => (39.857220, -75.342220),
+            Self::CrumLynne => (39.871940, -75.331110),
+            Self::RidleyPark => (39.880550, -75.322220),
+            Self::ProspectParkMoore => (39.888330, -75.308890),
+            Self::Norwood => (39.891670, -75.301670),
+            Self::Glenolden => (39.896390, -75.290000),
+            Self::Folcroft => (39.900550, -75.279720),
+            Self::SharonHill => (39.904450, -75.270840),
+            Self::CurtisPark => (39.908050, -75.265000),
+            Self::Darby => (39.913060, -75.254450),
+            Self::Allegheny => (40.003610, -75.164720),
+            Self::EastFalls => (40.011390, -75.191950),
+            Self::Wissahickon => (40.016670, -75.210280),
+            Self::Manayunk => (40.026940, -75.225000),
+            Self::IvyRidge => (40.034170, -75.235560),
+            Self::Miquon => (40.058610, -75.266390),
+            Self::SpringMill => (40.074170, -75.286110),
+            Self::Conshohocken => (40.072220, -75.308610),
+            Self::NorristownTransitCenter => (40.112780, -75.344170),
+            Self::MainStreet => (40.117220, -75.348610),
+            Self::NorristownElmStreet => (40.120830, -75.345000),
+            Self::Wawa => (39.900680, -75.458560),
+            Self::Elwyn => (39.907500, -75.411670),
+            Self::Media => (39.914440, -75.395000),
+            Self::MoylanRoseValley => (39.906110, -75.388610),
+            Self::Wallingford => (39.903610, -75.371940),
+            Self::Swarthmore => (39.902220, -75.350830),
+            Self::MortonRutledge => (39.907780, -75.328890),
+            Self::Secane => (39.915830, -75.309720),
+            Self::Primos => (39.921670, -75.298330),
+            Self::CliftonAldan => (39.926670, -75.290280),
+            Self::Gladstone => (39.932780, -75.282220),
+            Self::Lansdowne => (39.937500, -75.270840),
+            Self::FernwoodYeadon => (39.939720, -75.255840),
+            Self::Angora => (39.944720, -75.238610),
+            Self::FortyNinthStreet => (39.943610, -75.216670),
+            Self::Noble => (40.104440, -75.124170),
+            Self::Rydal => (40.107500, -75.110560),
+            Self::Meadowbrook => (40.111390, -75.092500),
+            Self::Bethayres => (40.116660, -75.068340),
+            Self::Philmont => (40.121940, -75.043610),
+            Self::ForestHills => (40.127780, -75.020550),
+            Self::Somerton => (40.130550, -75.011950),
+            Self::Trevose => (40.140280, -74.982500),
+            Self::NeshaminyFalls => (40.146950, -74.961670),
+            Self::Langhorne => (40.160830, -74.912500),
+            Self::Woodbourne => (40.192500, -74.889170),
+            Self::Yardley => (40.235280, -74.830560),
+            Self::WestTrenton => (40.257780, -74.815280),
+            Self::AirportTerminalEF => (39.879440, -75.239720),
+            Self::AirportTerminalCD => (39.878060, -75.240000),
+            Self::AirportTerminalB => (39.877220, -75.241390),
+            Self::AirportTerminalA => (39.876110, -75.245280),
+            Self::Eastwick => (39.892780, -75.243890),
+            Self::PennMedicineStation => (39.948060, -75.190280),
+            Self::FernRockTC => (40.040550, -75.134720),
+            Self::MelrosePark => (40.059440, -75.129170),
+            Self::ElkinsPark => (40.071390, -75.127780),
+            Self::JenkintownWyncote => (40.092780, -75.137500),
+            Self::Glenside => (40.101390, -75.153610),
+            Self::Ardsley => (40.114170, -75.153050),
+            Self::Roslyn => (40.120830, -75.134160),
+            Self::Crestmont => (40.133340, -75.118610),
+            Self::WillowGrove => (40.143890, -75.114170),
+            Self::Hatboro => (40.176110, -75.102500),
+            Self::Warminster => (40.195280, -75.089160),
+            Self::Thorndale => (39.992780, -75.763610),
+            Self::Downingtown => (40.002190, -75.710780),
+            Self::Whitford => (40.014720, -75.638050),
+            Self::Exton => (40.019290, -75.621710),
+            Self::Malvern => (40.036390, -75.515560),
+            Self::Paoli => (40.042760, -75.483760),
+            Self::Daylesford => (40.043060, -75.460560),
+            Self::Berwyn => (40.048050, -75.442220),
+            Self::Devon => (40.047220, -75.422780),
+            Self::Strafford => (40.049450, -75.403050),
+            Self::Wayne => (40.045830, -75.386670),
+            Self::StDavids => (40.043890, -75.372500),
+            Self::Radnor => (40.044720, -75.358890),
+            Self::Villanova => (40.038330, -75.341670),
+            Self::Rosemont => (40.027780, -75.326670),
+            Self::BrynMawr => (40.021950, -75.316390),
+            Self::Haverford => (40.013890, -75.299720),
+            Self::Ardmore => (40.008280, -75.290400),
+            Self::Wynnewood => (40.002780, -75.272500),
+            Self::Narberth => (40.004720, -75.261390),
+            Self::Merion => (39.998610, -75.251390),
+            Self::Overbrook => (39.989440, -75.249440),
+            Self::NorthHills => (40.111950, -75.169440),
+            Self::Oreland => (40.118330, -75.183890),
+            Self::FortWashington => (40.135830, -75.212220),
+            Self::Ambler => (40.153610, -75.224720),
+            Self::Penllyn => (40.170000, -75.244160),
+            Self::GwyneddValley => (40.184720, -75.256940),
+            Self::NorthWales => (40.214170, -75.277220),
+            Self::Pennbrook => (40.230280, -75.281670),
+            Self::Lansdale => (40.242780, -75.285000),
+            Self::Fortuna => (40.259450, -75.266110),
+            Self::Colmar => (40.268330, -75.254450),
+            Self::LinkBelt => (40.273890, -75.246670),
+            Self::Chalfont => (40.287780, -75.209720),
+            Self::NewBritain => (40.297500, -75.179730),
+            Self::DelawareValleyUniversity => (40.297220, -75.161670),
+            Self::Doylestown => (40.306390, -75.130280),
+            Self::NinthStreetLansdale => (40.250000, -75.279170),
+            Self::Trenton => (40.218510, -74.753930),
+            Self::Levittown => (40.140280, -74.816950),
+            Self::Bristol => (40.104720, -74.854720),
+            Self::Croydon => (40.093610, -74.906670),
+            Self::Eddington => (40.083060, -74.933610),
+            Self::CornwellsHeights => (40.070900, -74.954320),
+            Self::Torresdale => (40.054440, -74.984440),
+            Self::HolmesburgJunction => (40.032780, -75.023610),
+            Self::Tacony => (40.023330, -75.038890),
+            Self::Bridesburg => (40.010560, -75.069730),
+            Self::NorthPhiladelphiaAmtrak => (39.996780, -75.155110),
+            Self::Wister => (40.036110, -75.161110),
+            Self::Germantown => (40.037500, -75.171670),
+            Self::WashingtonLane => (40.050830, -75.171390),
+            Self::Stenton => (40.060550, -75.178610),
+            Self::Sedgwick => (40.062780, -75.185280),
+            Self::MountAiry => (40.065280, -75.190830),
+            Self::Wyndmoor => (40.073330, -75.196660),
+            Self::Gravers => (40.077500, -75.201670),
+            Self::ChestnutHillEast => (40.081110, -75.207220),
+            Self::ChestnutHillWest => (40.076390, -75.208340),
+            Self::Highland => (40.070560, -75.211110),
+            Self::StMartins => (40.065830, -75.204440),
+            Self::RichardAllenLane => (40.057500, -75.194730),
+            Self::Carpenter => (40.051110, -75.191390),
+            Self::Upsal => (40.042500, -75.190000),
+            Self::Tulpehocken => (40.035280, -75.186940),
+            Self::CheltenAvenue => (40.030000, -75.180830),
+            Self::QueenLane => (40.023330, -75.178050),
+            Self::NorthPhiladelphiaSepta => (39.997780, -75.156390),
+            Self::Olney => (40.033330, -75.122780),
+            Self::Lawndale => (40.051390, -75.103060),
+            Self::Cheltenham => (40.058060, -75.092780),
+            Self::Ryers => (40.064170, -75.086390),
+            Self::FoxChase => (40.076390, -75.083340),
         }
     }
 }
 
+impl fmt::Display for KnownRegionalRailStop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", RegionalRailStop::from(*self))
+    }
+}
+
 #[derive(
-    Clone,
-    Debug,
-    Deserialize,
-    Display,
-    EnumString,
-    EnumCount,
-    EnumIter,
-    PartialEq,
-    Eq,
-    PartialOrd,
-    Ord,
-    Hash,
+    Clone, Debug, Display, EnumString, EnumCount, EnumIter, PartialEq, Eq, PartialOrd, Ord, Hash,
 )]
 pub enum NhslStop {
     #[strum(serialize = "Norristown Transportation Center - NHSL")]
@@ -813,3 +1677,278 @@ pub enum NhslStop {
     #[strum(serialize = "69th St Transportation Center - NHSL")]
     SixtyNinthStTransportationCenter,
 }
+
+impl NhslStop {
+    /// The suffix SEPTA adds to the name of every NHSL stop
+    const NAME_SUFFIX: &'static str = " - NHSL";
+
+    /// Returns the stops of the line ordered from Norristown Transportation Center to 69th St
+    /// Transportation Center, the order a southbound vehicle visits them
+    pub fn stops() -> Vec<NhslStop> {
+        Self::iter().collect()
+    }
+
+    /// Returns the stops of the line in the order a vehicle travelling in `direction` visits them
+    ///
+    /// Vehicles heading to Norristown are northbound and vehicles heading to 69th St are
+    /// southbound.
+    pub fn stops_in_direction(direction: Direction) -> Vec<NhslStop> {
+        let mut stops = Self::stops();
+
+        if direction == Direction::North {
+            stops.reverse();
+        }
+
+        stops
+    }
+
+    /// Returns the stop a vehicle travelling in `direction` visits after this one, `None` at the
+    /// end of the line
+    pub fn next_stop(&self, direction: Direction) -> Option<NhslStop> {
+        let stops = Self::stops_in_direction(direction);
+        let position = stops.iter().position(|stop| stop == self)?;
+        stops.get(position + 1).cloned()
+    }
+
+    /// Returns the stop a vehicle travelling in `direction` visits before this one, `None` at the
+    /// start of the line
+    pub fn previous_stop(&self, direction: Direction) -> Option<NhslStop> {
+        let stops = Self::stops_in_direction(direction);
+        let position = stops.iter().position(|stop| stop == self)?;
+        position
+            .checked_sub(1)
+            .map(|position| stops[position].clone())
+    }
+
+    /// Parses a stop name with or without the ` - NHSL` suffix
+    pub(crate) fn from_name(name: &str) -> Option<NhslStop> {
+        let name = name.trim();
+
+        NhslStop::from_str(name)
+            .or_else(|_| NhslStop::from_str(&format!("{}{}", name, Self::NAME_SUFFIX)))
+            .ok()
+    }
+}
+
+#[derive(
+    Clone, Debug, Display, EnumString, EnumCount, EnumIter, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum MarketFrankfordStop {
+    #[strum(serialize = "69th St Transportation Center - MFL")]
+    SixtyNinthStTransportationCenter,
+    #[strum(serialize = "Millbourne Station - MFL")]
+    MillbourneStation,
+    #[strum(serialize = "63rd St Station - MFL")]
+    SixtyThirdStStation,
+    #[strum(serialize = "60th St Station - MFL")]
+    SixtiethStStation,
+    #[strum(serialize = "56th St Station - MFL")]
+    FiftySixthStStation,
+    #[strum(serialize = "52nd St Station - MFL")]
+    FiftySecondStStation,
+    #[strum(serialize = "46th St Station - MFL")]
+    FortySixthStStation,
+    #[strum(serialize = "40th St Station - MFL")]
+    FortiethStStation,
+    #[strum(serialize = "34th St Station - MFL")]
+    ThirtyFourthStStation,
+    #[strum(serialize = "30th St Station - MFL")]
+    ThirtiethStStation,
+    #[strum(serialize = "15th St Station - MFL")]
+    FifteenthStStation,
+    #[strum(serialize = "13th St Station - MFL")]
+    ThirteenthStStation,
+    #[strum(serialize = "11th St Station - MFL")]
+    EleventhStStation,
+    #[strum(serialize = "8th St Station - MFL")]
+    EighthStStation,
+    #[strum(serialize = "5th St Independence Hall Station - MFL")]
+    FifthStIndependenceHallStation,
+    #[strum(serialize = "2nd St Station - MFL")]
+    SecondStStation,
+    #[strum(serialize = "Spring Garden Station - MFL")]
+    SpringGardenStation,
+    #[strum(serialize = "Girard Station - MFL")]
+    GirardStation,
+    #[strum(serialize = "Berks Station - MFL")]
+    BerksStation,
+    #[strum(serialize = "York-Dauphin Station - MFL")]
+    YorkDauphinStation,
+    #[strum(serialize = "Huntingdon Station - MFL")]
+    HuntingdonStation,
+    #[strum(serialize = "Somerset Station - MFL")]
+    SomersetStation,
+    #[strum(serialize = "Allegheny Station - MFL")]
+    AlleghenyStation,
+    #[strum(serialize = "Tioga Station - MFL")]
+    TiogaStation,
+    #[strum(serialize = "Erie-Torresdale Station - MFL")]
+    ErieTorresdaleStation,
+    #[strum(serialize = "Church Station - MFL")]
+    ChurchStation,
+    #[strum(serialize = "Margaret-Orthodox Station - MFL")]
+    MargaretOrthodoxStation,
+    #[strum(serialize = "Frankford Transportation Center - MFL")]
+    FrankfordTransportationCenter,
+}
+
+#[derive(
+    Clone, Debug, Display, EnumString, EnumCount, EnumIter, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum BroadStreetStop {
+    #[strum(serialize = "Fern Rock Transportation Center - BSL")]
+    FernRockTransportationCenter,
+    #[strum(serialize = "Olney Transportation Center - BSL")]
+    OlneyTransportationCenter,
+    #[strum(serialize = "Logan Station - BSL")]
+    LoganStation,
+    #[strum(serialize = "Wyoming Station - BSL")]
+    WyomingStation,
+    #[strum(serialize = "Hunting Park Station - BSL")]
+    HuntingParkStation,
+    #[strum(serialize = "Erie Station - BSL")]
+    ErieStation,
+    #[strum(serialize = "Allegheny Station - BSL")]
+    AlleghenyStation,
+    #[strum(serialize = "North Philadelphia Station - BSL")]
+    NorthPhiladelphiaStation,
+    #[strum(serialize = "Susquehanna-Dauphin Station - BSL")]
+    SusquehannaDauphinStation,
+    #[strum(serialize = "Cecil B Moore Station - BSL")]
+    CecilBMooreStation,
+    #[strum(serialize = "Girard Station - BSL")]
+    GirardStation,
+    #[strum(serialize = "Fairmount Station - BSL")]
+    FairmountStation,
+    #[strum(serialize = "Spring Garden Station - BSL")]
+    SpringGardenStation,
+    #[strum(serialize = "Race-Vine Station - BSL")]
+    RaceVineStation,
+    #[strum(serialize = "City Hall Station - BSL")]
+    CityHallStation,
+    #[strum(serialize = "Walnut-Locust Station - BSL")]
+    WalnutLocustStation,
+    #[strum(serialize = "Lombard-South Station - BSL")]
+    LombardSouthStation,
+    #[strum(serialize = "Ellsworth-Federal Station - BSL")]
+    EllsworthFederalStation,
+    #[strum(serialize = "Tasker-Morris Station - BSL")]
+    TaskerMorrisStation,
+    #[strum(serialize = "Snyder Station - BSL")]
+    SnyderStation,
+    #[strum(serialize = "Oregon Station - BSL")]
+    OregonStation,
+    #[strum(serialize = "NRG Station - BSL")]
+    NrgStation,
+
+    // Broad-Ridge Spur Stops
+    #[strum(serialize = "Chinatown Station - BSL")]
+    ChinatownStation,
+    #[strum(serialize = "8th-Market Station - BSL")]
+    EighthMarketStation,
+}
+
+impl MarketFrankfordStop {
+    /// Returns the stops of the line ordered from 69th St Transportation Center to Frankford
+    /// Transportation Center, the order an eastbound train visits them
+    pub fn stops() -> Vec<MarketFrankfordStop> {
+        Self::iter().collect()
+    }
+
+    /// Returns the stops a rider can transfer to from this stop without leaving the station
+    pub fn transfers(&self) -> Vec<TransitStop> {
+        TransitStop::MarketFrankford(self.clone()).transfers()
+    }
+}
+
+impl BroadStreetStop {
+    /// Returns the stops of the main line ordered from Fern Rock Transportation Center to NRG
+    /// Station, the order a southbound train visits them
+    pub fn stops() -> Vec<BroadStreetStop> {
+        Self::iter()
+            .take_while(|stop| *stop != Self::ChinatownStation)
+            .collect()
+    }
+
+    /// Returns the stops of the Broad-Ridge Spur ordered from Girard Station to 8th-Market
+    /// Station, the order a southbound spur train visits them
+    ///
+    /// Spur trains share the main line down to Fairmount Station before branching off under Ridge
+    /// Ave.
+    pub fn ridge_spur_stops() -> Vec<BroadStreetStop> {
+        vec![
+            Self::GirardStation,
+            Self::FairmountStation,
+            Self::ChinatownStation,
+            Self::EighthMarketStation,
+        ]
+    }
+
+    /// Returns the stops a rider can transfer to from this stop without leaving the station
+    pub fn transfers(&self) -> Vec<TransitStop> {
+        TransitStop::BroadStreet(self.clone()).transfers()
+    }
+}
+
+/// A stop on any of the lines that have a stop type
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TransitStop {
+    RegionalRail(RegionalRailStop),
+    Nhsl(NhslStop),
+    MarketFrankford(MarketFrankfordStop),
+    BroadStreet(BroadStreetStop),
+}
+
+impl fmt::Display for TransitStop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RegionalRail(stop) => write!(f, "{}", stop),
+            Self::Nhsl(stop) => write!(f, "{}", stop),
+            Self::MarketFrankford(stop) => write!(f, "{}", stop),
+            Self::BroadStreet(stop) => write!(f, "{}", stop),
+        }
+    }
+}
+
+impl TransitStop {
+    /// Returns the groups of stops that share a station or are connected by a concourse
+    fn transfer_groups() -> Vec<Vec<TransitStop>> {
+        vec![
+            vec![
+                Self::MarketFrankford(MarketFrankfordStop::FifteenthStStation),
+                Self::BroadStreet(BroadStreetStop::CityHallStation),
+                Self::RegionalRail(RegionalRailStop::SuburbanStation),
+            ],
+            vec![
+                Self::MarketFrankford(MarketFrankfordStop::EleventhStStation),
+                Self::RegionalRail(RegionalRailStop::JeffersonStation),
+            ],
+            vec![
+                Self::MarketFrankford(MarketFrankfordStop::EighthStStation),
+                Self::BroadStreet(BroadStreetStop::EighthMarketStation),
+            ],
+            vec![
+                Self::MarketFrankford(MarketFrankfordStop::ThirtiethStStation),
+                Self::RegionalRail(RegionalRailStop::Gray30thStreet),
+            ],
+            vec![
+                Self::MarketFrankford(MarketFrankfordStop::SixtyNinthStTransportationCenter),
+                Self::Nhsl(NhslStop::SixtyNinthStTransportationCenter),
+            ],
+            vec![
+                Self::BroadStreet(BroadStreetStop::FernRockTransportationCenter),
+                Self::RegionalRail(RegionalRailStop::FernRockTC),
+            ],
+        ]
+    }
+
+    /// Returns the stops a rider can transfer to from this stop without leaving the station
+    pub fn transfers(&self) -> Vec<TransitStop> {
+        Self::transfer_groups()
+            .into_iter()
+            .filter(|group| group.contains(self))
+            .flatten()
+            .filter(|stop| stop != self)
+            .collect()
+    }
+}
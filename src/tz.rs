@@ -0,0 +1,97 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+
+use crate::responses::{Arrivals, BusSchedule, NextToArrive, RailSchedule};
+
+/// The timezone SEPTA reports every time in
+pub const SEPTA_TIME_ZONE: Tz = chrono_tz::America::New_York;
+
+/// Converts a naive SEPTA local time into a timezone aware time in [`SEPTA_TIME_ZONE`]
+///
+/// Daylight saving time transitions are resolved as a rider would read a clock:
+///
+/// * Times repeated when the clocks fall back in November resolve to the first occurrence, which
+///   is still on daylight time.
+/// * Times skipped when the clocks spring forward in March do not exist, they resolve to the same
+///   instant as the clock before the transition would have shown, so `2:30 AM` is `3:30 AM` EDT.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use septa_api::tz::to_septa_time;
+///
+/// let naive = NaiveDate::from_ymd_opt(2023, 11, 5)
+///     .unwrap()
+///     .and_hms_opt(1, 30, 0)
+///     .unwrap();
+///
+/// assert_eq!(to_septa_time(naive).to_rfc3339(), "2023-11-05T01:30:00-04:00");
+/// ```
+pub fn to_septa_time(naive: NaiveDateTime) -> DateTime<Tz> {
+    match SEPTA_TIME_ZONE.from_local_datetime(&naive).earliest() {
+        Some(date_time) => date_time,
+        None => {
+            // Interpret the time with the offset in effect before the gap, no transition is
+            // longer than a day so the offset a day earlier is the one before the gap
+            let offset_before_gap = SEPTA_TIME_ZONE
+                .offset_from_utc_datetime(&(naive - Duration::days(1)))
+                .fix();
+
+            SEPTA_TIME_ZONE.from_utc_datetime(&(naive - offset_before_gap))
+        }
+    }
+}
+
+impl Arrivals {
+    /// Returns [`Arrivals::sched_time`] in [`SEPTA_TIME_ZONE`]
+    pub fn sched_time_local(&self) -> DateTime<Tz> {
+        to_septa_time(self.sched_time)
+    }
+
+    /// Returns [`Arrivals::depart_time`] in [`SEPTA_TIME_ZONE`]
+    pub fn depart_time_local(&self) -> DateTime<Tz> {
+        to_septa_time(self.depart_time)
+    }
+}
+
+impl NextToArrive {
+    /// Returns the departure from the starting station anchored to the service day starting on
+    /// `service_date` in [`SEPTA_TIME_ZONE`]
+    pub fn departure_time_local(&self, service_date: NaiveDate) -> DateTime<Tz> {
+        to_septa_time(self.departure_date_time(service_date))
+    }
+
+    /// Returns the arrival at the ending station anchored to the service day starting on
+    /// `service_date` in [`SEPTA_TIME_ZONE`]
+    pub fn arrival_time_local(&self, service_date: NaiveDate) -> DateTime<Tz> {
+        to_septa_time(self.arrival_date_time(service_date))
+    }
+}
+
+impl RailSchedule {
+    /// Returns [`RailSchedule::scheduled_time`] anchored to the service day starting on
+    /// `service_date` in [`SEPTA_TIME_ZONE`]
+    pub fn scheduled_time_local(&self, service_date: NaiveDate) -> DateTime<Tz> {
+        to_septa_time(self.scheduled_date_time(service_date))
+    }
+
+    /// Returns [`RailSchedule::estimated_time`] anchored to the service day starting on
+    /// `service_date` in [`SEPTA_TIME_ZONE`]
+    pub fn estimated_time_local(&self, service_date: NaiveDate) -> DateTime<Tz> {
+        to_septa_time(self.estimated_date_time(service_date))
+    }
+
+    /// Returns [`RailSchedule::actual_time`] anchored to the service day starting on
+    /// `service_date` in [`SEPTA_TIME_ZONE`]
+    pub fn actual_time_local(&self, service_date: NaiveDate) -> Option<DateTime<Tz>> {
+        self.actual_date_time(service_date).map(to_septa_time)
+    }
+}
+
+impl BusSchedule {
+    /// Returns [`BusSchedule::scheduled_time`] in [`SEPTA_TIME_ZONE`]
+    pub fn scheduled_time_local(&self) -> DateTime<Tz> {
+        to_septa_time(self.scheduled_time)
+    }
+}
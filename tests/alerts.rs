@@ -0,0 +1,249 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use mockito::{Mock, ServerGuard};
+use septa_api::{
+    requests::AlertsRequest,
+    types::{AlertRoute, RegionalRailsLine},
+    Client,
+};
+use strum::IntoEnumIterator;
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+fn date_time(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> NaiveDateTime {
+    NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+        NaiveTime::from_hms_opt(hour, min, sec).unwrap(),
+    )
+}
+
+#[test]
+fn test_alert_route_round_trip() {
+    for line in RegionalRailsLine::iter() {
+        let route = AlertRoute::from(line.clone());
+        assert_eq!(
+            route.to_string(),
+            format!("rr_route_{}", line.id().to_lowercase())
+        );
+        assert_eq!(route.to_string().parse::<AlertRoute>(), Ok(route));
+    }
+
+    assert_eq!("generic".parse::<AlertRoute>(), Ok(AlertRoute::Generic));
+    assert_eq!(
+        "bus_route_17".parse::<AlertRoute>(),
+        Ok(AlertRoute::Bus("17".parse().unwrap()))
+    );
+    assert_eq!(
+        "trolley_route_10".parse::<AlertRoute>(),
+        Ok(AlertRoute::Trolley("10".parse().unwrap()))
+    );
+    assert_eq!(
+        "rr_route_gc".parse::<AlertRoute>(),
+        Ok(AlertRoute::Other("rr_route_gc".to_string()))
+    );
+    assert_eq!(
+        "bus_route_".parse::<AlertRoute>(),
+        Ok(AlertRoute::Other("bus_route_".to_string()))
+    );
+}
+
+#[tokio::test]
+async fn test_alerts_summary_deserialize1_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/Alerts/index.php")
+        .with_body(
+            r#"
+        [
+            {
+                "route_id": "generic",
+                "route_name": "Generic",
+                "mode": "generic",
+                "isadvisory": "No",
+                "isdetour": "No",
+                "isalert": "No",
+                "issuppend": "No",
+                "last_updated": "",
+                "isSnow": "N"
+            },
+            {
+                "route_id": "rr_route_pao",
+                "route_name": "Paoli/Thorndale",
+                "mode": "Regional Rail",
+                "isadvisory": "Yes",
+                "isdetour": "No",
+                "isalert": "No",
+                "issuppend": "No",
+                "last_updated": "Jun 13 2023 10:23:12:000AM",
+                "isSnow": "N"
+            },
+            {
+                "route_id": "bus_route_17",
+                "route_name": "17",
+                "mode": "Bus",
+                "isadvisory": "No",
+                "isdetour": "Yes",
+                "isalert": "Yes",
+                "issuppend": "No",
+                "last_updated": "Jun  4 2023 01:05:00:000PM",
+                "isSnow": "Y"
+            }
+        ]"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client.alerts_summary().await?;
+
+    assert_eq!(response.len(), 3);
+
+    assert_eq!(response[0].route, AlertRoute::Generic);
+    assert_eq!(response[0].last_updated, None);
+    assert!(!response[0].is_advisory);
+
+    assert_eq!(
+        response[1].route,
+        AlertRoute::RegionalRail(RegionalRailsLine::PaoliThorndale)
+    );
+    assert_eq!(response[1].route_name, "Paoli/Thorndale");
+    assert_eq!(response[1].mode, "Regional Rail");
+    assert!(response[1].is_advisory);
+    assert!(!response[1].is_detour);
+    assert!(!response[1].is_alert);
+    assert!(!response[1].is_suspended);
+    assert_eq!(
+        response[1].last_updated,
+        Some(date_time(2023, 6, 13, 10, 23, 12))
+    );
+    assert!(!response[1].is_snow);
+
+    assert_eq!(response[2].route, AlertRoute::Bus("17".parse().unwrap()));
+    assert!(response[2].is_detour);
+    assert!(response[2].is_alert);
+    assert_eq!(
+        response[2].last_updated,
+        Some(date_time(2023, 6, 4, 13, 5, 0))
+    );
+    assert!(response[2].is_snow);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_alerts_route_deserialize1_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server =
+        create_mock_server(&mut server, "/Alerts/get_alert_data.php?req1=rr_route_pao")
+            .with_body(
+                r#"
+    [
+        {
+            "route_id": "rr_route_pao",
+            "route_name": "Paoli/Thorndale",
+            "current_message": "",
+            "advisory_message": "<p>Track work between Paoli and Malvern</p>",
+            "detour_message": "",
+            "detour_id": "",
+            "detour_start_location": "",
+            "detour_start_date_time": "",
+            "detour_end_date_time": "",
+            "detour_reason": "",
+            "last_updated": "Jun 13 2023 10:23:12:000AM",
+            "isSnow": "N"
+        }
+    ]"#,
+            )
+            .create_async()
+            .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .alerts(AlertsRequest {
+            route: Some(RegionalRailsLine::PaoliThorndale.into()),
+        })
+        .await?;
+
+    assert_eq!(response.len(), 1);
+    assert_eq!(
+        response[0].route,
+        AlertRoute::RegionalRail(RegionalRailsLine::PaoliThorndale)
+    );
+    assert_eq!(response[0].current_message, None);
+    assert_eq!(
+        response[0].advisory_message,
+        Some("<p>Track work between Paoli and Malvern</p>".to_string())
+    );
+    assert_eq!(response[0].detour, None);
+    assert_eq!(
+        response[0].last_updated,
+        Some(date_time(2023, 6, 13, 10, 23, 12))
+    );
+    assert!(!response[0].is_snow);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_alerts_all_deserialize1_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/Alerts/get_alert_data.php?req1=all")
+        .with_body(
+            r#"
+        [
+            {
+                "route_id": "bus_route_17",
+                "route_name": "17",
+                "current_message": "Expect delays due to a disabled vehicle",
+                "advisory_message": "",
+                "detour_message": "Detoured via 19th St",
+                "detour_id": "4123",
+                "detour_start_location": "20th & Johnston",
+                "detour_start_date_time": "6/13/2023   9:00 AM",
+                "detour_end_date_time": "6/14/2023   5:00 PM",
+                "detour_reason": "Construction",
+                "last_updated": "Jun 13 2023 09:01:44:000AM",
+                "isSnow": "N"
+            }
+        ]"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client.alerts(AlertsRequest { route: None }).await?;
+
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0].route, AlertRoute::Bus("17".parse().unwrap()));
+    assert_eq!(
+        response[0].current_message,
+        Some("Expect delays due to a disabled vehicle".to_string())
+    );
+    assert_eq!(response[0].advisory_message, None);
+
+    let detour = response[0].detour.as_ref().expect("detour should be set");
+    assert_eq!(detour.id, Some("4123".to_string()));
+    assert_eq!(detour.message, Some("Detoured via 19th St".to_string()));
+    assert_eq!(detour.start_location, Some("20th & Johnston".to_string()));
+    assert_eq!(
+        detour.start_date_time,
+        NaiveDate::from_ymd_opt(2023, 6, 13)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+    );
+    assert_eq!(
+        detour.end_date_time,
+        NaiveDate::from_ymd_opt(2023, 6, 14)
+            .unwrap()
+            .and_hms_opt(17, 0, 0)
+    );
+    assert_eq!(detour.reason, Some("Construction".to_string()));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use mockito::{Mock, ServerGuard};
 use septa_api::{
     requests::{self, ArrivalsRequest, Direction},
-    types::{self, RegionalRailStop, RegionalRailsLine, ServiceType},
+    types::{
+        self, RegionalRailStop, RegionalRailsLine, ServiceType, Track, TrackAssignment, TrainStatus,
+    },
     Client,
 };
 
@@ -139,7 +141,7 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(arrival1.origin, RegionalRailStop::Thorndale);
     assert_eq!(arrival1.destination, RegionalRailStop::WestTrenton);
     assert_eq!(arrival1.line, Some(RegionalRailsLine::WestTrenton));
-    assert_eq!(arrival1.status, "12 min");
+    assert_eq!(arrival1.status, TrainStatus::Late(Duration::minutes(12)));
     assert_eq!(arrival1.service_type, ServiceType::Local);
     assert_eq!(
         arrival1.next_station,
@@ -159,9 +161,9 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
             NaiveTime::from_hms_milli_opt(18, 30, 0, 0).unwrap()
         )
     );
-    assert_eq!(arrival1.track, "2");
+    assert_eq!(arrival1.track, Some("2".parse::<Track>()?));
     assert_eq!(arrival1.track_change, None);
-    assert_eq!(arrival1.platform, "");
+    assert_eq!(arrival1.platform, None);
     assert_eq!(arrival1.platform_change, None);
 
     // Check the second arrival
@@ -172,7 +174,7 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(arrival2.origin, RegionalRailStop::ChestnutHillWest);
     assert_eq!(arrival2.destination, RegionalRailStop::TempleUniversity);
     assert_eq!(arrival2.line, Some(RegionalRailsLine::FoxChase));
-    assert_eq!(arrival2.status, "On Time");
+    assert_eq!(arrival2.status, TrainStatus::OnTime);
     assert_eq!(arrival2.service_type, ServiceType::Local);
     assert_eq!(
         arrival2.next_station,
@@ -192,9 +194,9 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
             NaiveTime::from_hms_milli_opt(18, 37, 0, 0).unwrap()
         )
     );
-    assert_eq!(arrival2.track, "1");
+    assert_eq!(arrival2.track, Some("1".parse::<Track>()?));
     assert_eq!(arrival2.track_change, None);
-    assert_eq!(arrival2.platform, "");
+    assert_eq!(arrival2.platform, None);
     assert_eq!(arrival2.platform_change, None);
 
     // Check the third arrival
@@ -205,7 +207,7 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(arrival3.origin, RegionalRailStop::AirportTerminalEF);
     assert_eq!(arrival3.destination, RegionalRailStop::FoxChase);
     assert_eq!(arrival3.line, Some(RegionalRailsLine::FoxChase));
-    assert_eq!(arrival3.status, "On Time");
+    assert_eq!(arrival3.status, TrainStatus::OnTime);
     assert_eq!(arrival3.service_type, ServiceType::Local);
     assert_eq!(
         arrival3.next_station,
@@ -225,9 +227,9 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
             NaiveTime::from_hms_milli_opt(18, 45, 0, 0).unwrap()
         )
     );
-    assert_eq!(arrival3.track, "1");
+    assert_eq!(arrival3.track, Some("1".parse::<Track>()?));
     assert_eq!(arrival3.track_change, None);
-    assert_eq!(arrival3.platform, "");
+    assert_eq!(arrival3.platform, None);
     assert_eq!(arrival3.platform_change, None);
 
     // Check the fourth arrival
@@ -238,7 +240,7 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(arrival4.origin, RegionalRailStop::Trenton);
     assert_eq!(arrival4.destination, RegionalRailStop::TempleUniversity);
     assert_eq!(arrival4.line, Some(RegionalRailsLine::Trenton));
-    assert_eq!(arrival4.status, "On Time");
+    assert_eq!(arrival4.status, TrainStatus::OnTime);
     assert_eq!(arrival4.service_type, ServiceType::Local);
     assert_eq!(
         arrival4.next_station,
@@ -258,9 +260,9 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
             NaiveTime::from_hms_milli_opt(18, 59, 0, 0).unwrap()
         )
     );
-    assert_eq!(arrival4.track, "2");
+    assert_eq!(arrival4.track, Some("2".parse::<Track>()?));
     assert_eq!(arrival4.track_change, None);
-    assert_eq!(arrival4.platform, "");
+    assert_eq!(arrival4.platform, None);
     assert_eq!(arrival4.platform_change, None);
 
     // Check the fifth arrival
@@ -274,7 +276,7 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
         RegionalRailStop::NorristownTransitCenter
     );
     assert_eq!(arrival5.line, Some(RegionalRailsLine::MediaWawa));
-    assert_eq!(arrival5.status, "1 min");
+    assert_eq!(arrival5.status, TrainStatus::Late(Duration::minutes(1)));
     assert_eq!(arrival5.service_type, ServiceType::Local);
     assert_eq!(arrival5.next_station, Some(RegionalRailStop::Secane));
     assert_eq!(
@@ -291,9 +293,9 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
             NaiveTime::from_hms_milli_opt(19, 6, 0, 0).unwrap()
         )
     );
-    assert_eq!(arrival5.track, "1");
+    assert_eq!(arrival5.track, Some("1".parse::<Track>()?));
     assert_eq!(arrival5.track_change, None);
-    assert_eq!(arrival5.platform, "");
+    assert_eq!(arrival5.platform, None);
     assert_eq!(arrival5.platform_change, None);
 
     mock_server.assert_async().await;
@@ -430,7 +432,7 @@ async fn test_deserialize2_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(arrival1.origin, RegionalRailStop::Malvern);
     assert_eq!(arrival1.destination, RegionalRailStop::WestTrenton);
     assert_eq!(arrival1.line, Some(RegionalRailsLine::PaoliThorndale));
-    assert_eq!(arrival1.status, "On Time");
+    assert_eq!(arrival1.status, TrainStatus::OnTime);
     assert_eq!(arrival1.service_type, ServiceType::Local);
     assert_eq!(arrival1.next_station, None);
     assert_eq!(
@@ -447,9 +449,9 @@ async fn test_deserialize2_async() -> Result<(), septa_api::errors::Error> {
             NaiveTime::from_hms_milli_opt(18, 26, 0, 0).unwrap()
         )
     );
-    assert_eq!(arrival1.track, "1");
+    assert_eq!(arrival1.track, Some("1".parse::<Track>()?));
     assert_eq!(arrival1.track_change, None);
-    assert_eq!(arrival1.platform, "");
+    assert_eq!(arrival1.platform, None);
     assert_eq!(arrival1.platform_change, None);
 
     // Check the second arrival
@@ -460,7 +462,7 @@ async fn test_deserialize2_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(arrival2.origin, RegionalRailStop::Thorndale);
     assert_eq!(arrival2.destination, RegionalRailStop::WestTrenton);
     assert_eq!(arrival2.line, Some(RegionalRailsLine::PaoliThorndale));
-    assert_eq!(arrival2.status, "On Time");
+    assert_eq!(arrival2.status, TrainStatus::OnTime);
     assert_eq!(arrival2.service_type, ServiceType::Local);
     assert_eq!(arrival2.next_station, None);
     assert_eq!(
@@ -477,9 +479,9 @@ async fn test_deserialize2_async() -> Result<(), septa_api::errors::Error> {
             NaiveTime::from_hms_milli_opt(19, 26, 0, 0).unwrap()
         )
     );
-    assert_eq!(arrival2.track, "1");
+    assert_eq!(arrival2.track, Some("1".parse::<Track>()?));
     assert_eq!(arrival2.track_change, None);
-    assert_eq!(arrival2.platform, "");
+    assert_eq!(arrival2.platform, None);
     assert_eq!(arrival2.platform_change, None);
 
     // Check the third arrival
@@ -490,7 +492,7 @@ async fn test_deserialize2_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(arrival3.origin, RegionalRailStop::Thorndale);
     assert_eq!(arrival3.destination, RegionalRailStop::WestTrenton);
     assert_eq!(arrival3.line, Some(RegionalRailsLine::PaoliThorndale));
-    assert_eq!(arrival3.status, "On Time");
+    assert_eq!(arrival3.status, TrainStatus::OnTime);
     assert_eq!(arrival3.service_type, ServiceType::Local);
     assert_eq!(arrival3.next_station, None);
     assert_eq!(
@@ -507,9 +509,9 @@ async fn test_deserialize2_async() -> Result<(), septa_api::errors::Error> {
             NaiveTime::from_hms_milli_opt(20, 26, 0, 0).unwrap()
         )
     );
-    assert_eq!(arrival3.track, "1");
+    assert_eq!(arrival3.track, Some("1".parse::<Track>()?));
     assert_eq!(arrival3.track_change, None);
-    assert_eq!(arrival3.platform, "");
+    assert_eq!(arrival3.platform, None);
     assert_eq!(arrival3.platform_change, None);
 
     // Check the fourth arrival
@@ -520,7 +522,7 @@ async fn test_deserialize2_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(arrival4.origin, RegionalRailStop::Malvern);
     assert_eq!(arrival4.destination, RegionalRailStop::TempleUniversity);
     assert_eq!(arrival4.line, Some(RegionalRailsLine::PaoliThorndale));
-    assert_eq!(arrival4.status, "On Time");
+    assert_eq!(arrival4.status, TrainStatus::OnTime);
     assert_eq!(arrival4.service_type, ServiceType::Local);
     assert_eq!(arrival4.next_station, None);
     assert_eq!(
@@ -537,9 +539,9 @@ async fn test_deserialize2_async() -> Result<(), septa_api::errors::Error> {
             NaiveTime::from_hms_milli_opt(21, 31, 0, 0).unwrap()
         )
     );
-    assert_eq!(arrival4.track, "1");
+    assert_eq!(arrival4.track, Some("1".parse::<Track>()?));
     assert_eq!(arrival4.track_change, None);
-    assert_eq!(arrival4.platform, "");
+    assert_eq!(arrival4.platform, None);
     assert_eq!(arrival4.platform_change, None);
 
     // Check the fifth arrival
@@ -550,7 +552,7 @@ async fn test_deserialize2_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(arrival5.origin, RegionalRailStop::Thorndale);
     assert_eq!(arrival5.destination, RegionalRailStop::TempleUniversity);
     assert_eq!(arrival5.line, Some(RegionalRailsLine::PaoliThorndale));
-    assert_eq!(arrival5.status, "On Time");
+    assert_eq!(arrival5.status, TrainStatus::OnTime);
     assert_eq!(arrival5.service_type, ServiceType::Local);
     assert_eq!(arrival5.next_station, None);
     assert_eq!(
@@ -567,9 +569,9 @@ async fn test_deserialize2_async() -> Result<(), septa_api::errors::Error> {
             NaiveTime::from_hms_milli_opt(22, 41, 0, 0).unwrap()
         )
     );
-    assert_eq!(arrival5.track, "1");
+    assert_eq!(arrival5.track, Some("1".parse::<Track>()?));
     assert_eq!(arrival5.track_change, None);
-    assert_eq!(arrival5.platform, "");
+    assert_eq!(arrival5.platform, None);
     assert_eq!(arrival5.platform_change, None);
 
     assert_eq!(arrival_response.southbound.len(), 0);
@@ -646,3 +648,170 @@ async fn empty_deserialize_test() -> Result<(), septa_api::errors::Error> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_suspended_deserialize_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server =
+        create_mock_server(&mut server, "/Arrivals/index.php?station=Temple+University")
+            .with_body(
+                r#"
+        {
+            "Temple U Departures: April 11, 2023, 6:29 pm": [
+              {
+                "Northbound": [
+                  {
+                    "direction": "N",
+                    "path": "R5/3N",
+                    "train_id": "5344",
+                    "origin": "Thorndale",
+                    "destination": "West Trenton",
+                    "line": "West Trenton",
+                    "status": "Suspended",
+                    "service_type": "LOCAL",
+                    "next_station": "Suburban Station",
+                    "sched_time": "2023-04-11 18:30:00.000",
+                    "depart_time": "2023-04-11 18:30:00.000",
+                    "track": "2",
+                    "track_change": null,
+                    "platform": "",
+                    "platform_change": null
+                  }
+                ]
+              },
+              []
+            ]
+        }"#,
+            )
+            .create_async()
+            .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .arrivals(ArrivalsRequest {
+            station: RegionalRailStop::TempleUniversity,
+            results: None,
+            direction: None,
+        })
+        .await?;
+
+    assert_eq!(response.northbound.len(), 1);
+    assert_eq!(response.northbound[0].status, TrainStatus::Suspended);
+    assert_eq!(response.northbound[0].status.delay(), None);
+    assert!(response.southbound.is_empty());
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[test]
+fn test_train_status_deserialize() -> Result<(), serde_json::Error> {
+    // The statuses seen in the arrivals and next to arrive fixtures
+    let fixtures = [
+        ("On Time", TrainStatus::OnTime),
+        ("On time", TrainStatus::OnTime),
+        ("1 min", TrainStatus::Late(Duration::minutes(1))),
+        ("5 min", TrainStatus::Late(Duration::minutes(5))),
+        ("12 min", TrainStatus::Late(Duration::minutes(12))),
+    ];
+
+    // Made up statuses that are not in any fixture, they cover the other spellings the parser
+    // accepts and the fallback for statuses it does not know
+    let invented = [
+        ("12 mins", TrainStatus::Late(Duration::minutes(12))),
+        ("Suspended", TrainStatus::Suspended),
+        ("CANCELLED", TrainStatus::Cancelled),
+        ("Canceled", TrainStatus::Cancelled),
+        ("Delayed", TrainStatus::Other("Delayed".to_string())),
+        ("", TrainStatus::Other("".to_string())),
+    ];
+
+    for (fixture, expected) in fixtures.into_iter().chain(invented) {
+        let status: TrainStatus = serde_json::from_value(serde_json::json!(fixture))?;
+        assert_eq!(status, expected, "status {:?}", fixture);
+
+        let round_trip: TrainStatus = serde_json::from_value(serde_json::to_value(&status)?)?;
+        assert_eq!(round_trip, status, "status {:?}", fixture);
+    }
+
+    assert_eq!(TrainStatus::OnTime.delay(), Some(Duration::zero()));
+    assert_eq!(
+        TrainStatus::Late(Duration::minutes(12)).delay(),
+        Some(Duration::minutes(12))
+    );
+    assert_eq!(TrainStatus::Cancelled.delay(), None);
+    assert_eq!(
+        TrainStatus::Late(Duration::minutes(12)).to_string(),
+        "12 min"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_track_assignment_deserialize() -> Result<(), Box<dyn std::error::Error>> {
+    let arrivals: Vec<septa_api::responses::Arrivals> = serde_json::from_str(
+        r#"[
+            {
+                "direction": "N",
+                "path": "R5/3N",
+                "train_id": "5344",
+                "origin": "Thorndale",
+                "destination": "West Trenton",
+                "line": "West Trenton",
+                "status": "On Time",
+                "service_type": "LOCAL",
+                "next_station": "Suburban Station",
+                "sched_time": "2023-04-11 18:30:00.000",
+                "depart_time": "2023-04-11 18:30:00.000",
+                "track": "3",
+                "track_change": "5",
+                "platform": "A",
+                "platform_change": ""
+            },
+            {
+                "direction": "N",
+                "path": "R5/3N",
+                "train_id": "5346",
+                "origin": "Thorndale",
+                "destination": "West Trenton",
+                "line": "West Trenton",
+                "status": "On Time",
+                "service_type": "LOCAL",
+                "next_station": "Suburban Station",
+                "sched_time": "2023-04-11 19:30:00.000",
+                "depart_time": "2023-04-11 19:30:00.000",
+                "track": " ",
+                "track_change": null,
+                "platform": "B",
+                "platform_change": "B"
+            }
+        ]"#,
+    )?;
+
+    let track = arrivals[0].track_assignment();
+    assert_eq!(
+        track,
+        TrackAssignment {
+            scheduled: Some("3".parse()?),
+            changed_to: Some("5".parse()?),
+        }
+    );
+    assert!(track.is_changed());
+    assert_eq!(track.current(), Some(&"5".parse::<Track>()?));
+
+    let platform = arrivals[0].platform_assignment();
+    assert_eq!(platform.changed_to, None);
+    assert_eq!(platform.current(), Some(&"A".parse::<Track>()?));
+
+    // Blank tracks are missing and a change to the scheduled platform is not a change
+    assert_eq!(arrivals[1].track, None);
+    assert_eq!(arrivals[1].track_assignment(), TrackAssignment::default());
+    assert_eq!(arrivals[1].platform_change, Some("B".parse()?));
+    assert!(!arrivals[1].platform_assignment().is_changed());
+
+    assert!("".parse::<Track>().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use chrono::NaiveDate;
+use mockito::{Mock, ServerGuard};
+use septa_api::{
+    types::{BusRoute, TransportType},
+    Client,
+};
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+#[tokio::test]
+async fn test_bus_detours_deserialize1_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/BusDetours/index.php?req1=17")
+        .with_body(
+            r#"
+        [
+            {
+                "route_id": "17",
+                "route_info": [
+                    {
+                        "route_direction": "NB",
+                        "reason": "Construction",
+                        "start_location": "20th & Johnston",
+                        "end_location": "20th & Oregon",
+                        "start_date_time": "6/13/2023   9:00 AM",
+                        "end_date_time": "6/14/2023   5:00 PM",
+                        "current_message": "Detoured via 19th St"
+                    },
+                    {
+                        "route_direction": "SB",
+                        "reason": "Police Activity",
+                        "start_location": "Juniper & Walnut",
+                        "end_location": "",
+                        "start_date_time": "12/1/2023 11:45 PM",
+                        "end_date_time": "",
+                        "current_message": ""
+                    }
+                ]
+            }
+        ]"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client.bus_detours(Some("17".parse::<BusRoute>()?)).await?;
+
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0].route_id.route_id(), "17");
+
+    let detours = &response[0].detours;
+    assert_eq!(detours.len(), 2);
+
+    assert_eq!(detours[0].direction, Some("NB".to_string()));
+    assert_eq!(detours[0].reason, Some("Construction".to_string()));
+    assert_eq!(
+        detours[0].start_location,
+        Some("20th & Johnston".to_string())
+    );
+    assert_eq!(detours[0].end_location, Some("20th & Oregon".to_string()));
+    assert_eq!(
+        detours[0].start_date_time,
+        NaiveDate::from_ymd_opt(2023, 6, 13).and_then(|date| date.and_hms_opt(9, 0, 0))
+    );
+    assert_eq!(
+        detours[0].end_date_time,
+        NaiveDate::from_ymd_opt(2023, 6, 14).and_then(|date| date.and_hms_opt(17, 0, 0))
+    );
+    assert_eq!(
+        detours[0].current_message,
+        Some("Detoured via 19th St".to_string())
+    );
+
+    assert_eq!(
+        detours[1].start_date_time,
+        NaiveDate::from_ymd_opt(2023, 12, 1).and_then(|date| date.and_hms_opt(23, 45, 0))
+    );
+    assert_eq!(detours[1].end_location, None);
+    assert_eq!(detours[1].end_date_time, None);
+    assert_eq!(detours[1].current_message, None);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bus_detours_all_routes_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/BusDetours/index.php")
+        .with_body(
+            r#"
+        [
+            {
+                "route_id": "21",
+                "route_info": [
+                    {
+                        "route_direction": "EB",
+                        "reason": "Special Event",
+                        "start_location": "Chestnut & 33rd",
+                        "end_location": "Chestnut & 30th",
+                        "start_date_time": "6/13/2023 6:00 AM",
+                        "end_date_time": "6/13/2023 11:00 PM",
+                        "current_message": "Detoured via Walnut St"
+                    }
+                ]
+            },
+            {
+                "route_id": "G1",
+                "route_info": []
+            }
+        ]"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client.bus_detours(None).await?;
+
+    assert_eq!(response.len(), 2);
+    assert_eq!(response[0].route_id.route_id(), "21");
+    assert_eq!(response[0].detours.len(), 1);
+    assert_eq!(response[1].route_id.route_id(), "G1");
+    assert_eq!(
+        response[1].route_id.transport_type(),
+        TransportType::Trolley
+    );
+    assert!(response[1].detours.is_empty());
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bus_detours_empty_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/BusDetours/index.php?req1=LUCY")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .bus_detours(Some("LUCY".parse::<BusRoute>()?))
+        .await?;
+
+    assert!(response.is_empty());
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use chrono::{NaiveDate, Weekday};
+use mockito::{Mock, ServerGuard};
+use septa_api::{
+    requests::{BusDirection, BusSchedulesRequest},
+    types::{BusRoute, TransportType},
+    Client,
+};
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+#[tokio::test]
+async fn test_bus_schedules_deserialize1_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(
+        &mut server,
+        "/BusSchedules/index.php?req1=17842&req2=17&req3=i&req6=2",
+    )
+    .with_body(
+        r#"
+        {
+            "17": [
+                {
+                    "StopName": "Juniper St & Walnut St",
+                    "Route": "17",
+                    "date": "4:21p",
+                    "day": "Tue",
+                    "Direction": "1",
+                    "DateCalender": "04/25/23 04:21 pm",
+                    "DirectionDesc": "Front-Market"
+                },
+                {
+                    "StopName": "Juniper St & Walnut St",
+                    "Route": "17",
+                    "date": "12:03a",
+                    "day": "Wed",
+                    "Direction": "1",
+                    "DateCalender": "04/26/23 12:03 am",
+                    "DirectionDesc": "Front-Market"
+                }
+            ]
+        }"#,
+    )
+    .create_async()
+    .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .bus_schedules(BusSchedulesRequest {
+            stop_id: 17842,
+            route: Some("17".parse::<BusRoute>()?),
+            direction: Some(BusDirection::Inbound),
+            results: Some(2),
+        })
+        .await?;
+
+    assert_eq!(response.len(), 1);
+
+    let departures = &response[&"17".parse::<BusRoute>()?];
+    assert_eq!(departures.len(), 2);
+
+    assert_eq!(departures[0].stop_name, "Juniper St & Walnut St");
+    assert_eq!(departures[0].route.route_id(), "17");
+    assert_eq!(departures[0].route.transport_type(), TransportType::Bus);
+    assert_eq!(departures[0].day, Weekday::Tue);
+    assert_eq!(departures[0].direction, "1");
+    assert_eq!(departures[0].direction_description, "Front-Market");
+    assert_eq!(
+        departures[0].scheduled_time,
+        NaiveDate::from_ymd_opt(2023, 4, 25)
+            .unwrap()
+            .and_hms_opt(16, 21, 0)
+            .unwrap()
+    );
+
+    assert_eq!(departures[1].day, Weekday::Wed);
+    assert_eq!(
+        departures[1].scheduled_time,
+        NaiveDate::from_ymd_opt(2023, 4, 26)
+            .unwrap()
+            .and_hms_opt(0, 3, 0)
+            .unwrap()
+    );
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bus_schedules_multiple_routes_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/BusSchedules/index.php?req1=425")
+        .with_body(
+            r#"
+        {
+            "21": [
+                {
+                    "StopName": "Chestnut St & 15th St",
+                    "Route": "21",
+                    "date": "9:02a",
+                    "day": "Mon",
+                    "Direction": "0",
+                    "DateCalender": "06/12/23 09:02 am",
+                    "DirectionDesc": "69th St Transportation Center"
+                }
+            ],
+            "42": [
+                {
+                    "StopName": "Chestnut St & 15th St",
+                    "Route": "42",
+                    "date": "9:05a",
+                    "day": "Mon",
+                    "Direction": "0",
+                    "DateCalender": "06/12/23 09:05 am",
+                    "DirectionDesc": "Wycombe"
+                }
+            ]
+        }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .bus_schedules(BusSchedulesRequest {
+            stop_id: 425,
+            route: None,
+            direction: None,
+            results: None,
+        })
+        .await?;
+
+    assert_eq!(response.len(), 2);
+
+    let route_21 = "21".parse::<BusRoute>()?;
+    let route_42 = "42".parse::<BusRoute>()?;
+    assert_eq!(
+        response[&route_21][0].direction_description,
+        "69th St Transportation Center"
+    );
+    assert_eq!(response[&route_42][0].direction_description, "Wycombe");
+    assert_eq!(
+        response[&route_42][0].scheduled_time,
+        NaiveDate::from_ymd_opt(2023, 6, 12)
+            .unwrap()
+            .and_hms_opt(9, 5, 0)
+            .unwrap()
+    );
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bus_schedules_invalid_date_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/BusSchedules/index.php?req1=425")
+        .with_body(
+            r#"
+        {
+            "21": [
+                {
+                    "StopName": "Chestnut St & 15th St",
+                    "Route": "21",
+                    "date": "9:02a",
+                    "day": "Mon",
+                    "Direction": "0",
+                    "DateCalender": "2023-06-12 9:02",
+                    "DirectionDesc": "69th St Transportation Center"
+                }
+            ]
+        }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .bus_schedules(BusSchedulesRequest {
+            stop_id: 425,
+            route: None,
+            direction: None,
+            results: None,
+        })
+        .await;
+
+    match response {
+        Err(septa_api::errors::Error::DeserializeFailed { context, .. }) => {
+            let context = context.expect("error should have a context");
+            assert_eq!(context.json_path.as_deref(), Some("21[0].DateCalender"));
+        }
+        result => panic!("expected a deserialize error, got {:?}", result),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
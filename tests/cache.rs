@@ -0,0 +1,269 @@
+use std::time::Duration;
+
+use mockito::{Mock, ServerGuard};
+use septa_api::{cache::CacheConfig, requests::RailScheduleRequest, Client, Endpoint};
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+const TRAIN_VIEW_BODY: &str = r#"
+[
+    {
+        "lat":"39.954174265",
+        "lon":"-75.16763361",
+        "trainno":"2333",
+        "service":"LOCAL",
+        "dest":"Wawa",
+        "currentstop":"Suburban Station",
+        "nextstop":"30th Street Station",
+        "line":"Media\/Wawa",
+        "consist":"872,871,858,857",
+        "heading":"189.8775840187919",
+        "late":0,
+        "SOURCE":"Norristown Transit Center",
+        "TRACK":"",
+        "TRACK_CHANGE":""
+    }
+]"#;
+
+#[test]
+fn test_cache_config_ttl() {
+    let config = CacheConfig::new().with_endpoint_ttl(Endpoint::TrainView, Duration::from_secs(15));
+    assert_eq!(
+        config.ttl(Endpoint::TrainView),
+        Some(Duration::from_secs(15))
+    );
+    assert_eq!(config.ttl(Endpoint::Arrivals), None);
+
+    let config = config.with_default_ttl(Duration::from_secs(60));
+    assert_eq!(
+        config.ttl(Endpoint::TrainView),
+        Some(Duration::from_secs(15))
+    );
+    assert_eq!(
+        config.ttl(Endpoint::Arrivals),
+        Some(Duration::from_secs(60))
+    );
+}
+
+#[tokio::test]
+async fn test_cache_hit_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(TRAIN_VIEW_BODY)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .cache(CacheConfig::new().with_endpoint_ttl(Endpoint::TrainView, Duration::from_secs(15)))
+        .build()?;
+
+    let first = client.train_view().await?;
+    let second = client.clone().train_view().await?;
+
+    assert_eq!(first.len(), 1);
+    assert_eq!(second.len(), 1);
+    assert_eq!(first[0].train_number, second[0].train_number);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_coalesces_concurrent_requests_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_chunked_body(|writer| {
+            std::thread::sleep(Duration::from_millis(200));
+            writer.write_all(TRAIN_VIEW_BODY.as_bytes())
+        })
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .cache(CacheConfig::new().with_endpoint_ttl(Endpoint::TrainView, Duration::from_secs(15)))
+        .build()?;
+
+    let (first, second, third) = tokio::join!(
+        client.train_view(),
+        client.train_view(),
+        client.train_view()
+    );
+
+    assert_eq!(first?.len(), 1);
+    assert_eq!(second?.len(), 1);
+    assert_eq!(third?.len(), 1);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_keyed_by_params_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let first_mock = create_mock_server(&mut server, "/RRSchedules/index.php?req1=3236")
+        .with_body("[]")
+        .expect(1)
+        .create_async()
+        .await;
+    let second_mock = create_mock_server(&mut server, "/RRSchedules/index.php?req1=1729")
+        .with_body("[]")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .cache(CacheConfig::new().with_default_ttl(Duration::from_secs(300)))
+        .build()?;
+
+    for train_number in ["3236", "1729", "3236", "1729"] {
+        client
+            .rail_schedule(RailScheduleRequest {
+                train_number: train_number.to_string(),
+            })
+            .await?;
+    }
+
+    first_mock.assert_async().await;
+    second_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_expires_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(TRAIN_VIEW_BODY)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .cache(CacheConfig::new().with_endpoint_ttl(Endpoint::TrainView, Duration::from_millis(50)))
+        .build()?;
+
+    client.train_view().await?;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    client.train_view().await?;
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_clear_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(TRAIN_VIEW_BODY)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .cache(CacheConfig::new().with_default_ttl(Duration::from_secs(15)))
+        .build()?;
+
+    client.train_view().await?;
+    client.clear_cache();
+    client.train_view().await?;
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_skips_endpoints_without_ttl_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(TRAIN_VIEW_BODY)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .cache(CacheConfig::new().with_endpoint_ttl(Endpoint::Arrivals, Duration::from_secs(15)))
+        .build()?;
+
+    client.train_view().await?;
+    client.train_view().await?;
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_does_not_store_errors_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let failing_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_status(500)
+        .expect(1)
+        .create_async()
+        .await;
+    let success_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(TRAIN_VIEW_BODY)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .cache(CacheConfig::new().with_default_ttl(Duration::from_secs(15)))
+        .build()?;
+
+    assert!(client.train_view().await.is_err());
+    assert_eq!(client.cache_len(), 0);
+    assert_eq!(client.train_view().await?.len(), 1);
+    assert_eq!(client.train_view().await?.len(), 1);
+    assert_eq!(client.cache_len(), 1);
+
+    failing_mock.assert_async().await;
+    success_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_evicts_failed_requests_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/RRSchedules/index.php")
+        .match_query(mockito::Matcher::Any)
+        .with_status(500)
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .cache(CacheConfig::new().with_default_ttl(Duration::from_secs(300)))
+        .build()?;
+
+    // Every failed train number would otherwise leave an empty entry behind
+    for train_number in ["3236", "1729", "9999"] {
+        assert!(client
+            .rail_schedule(RailScheduleRequest {
+                train_number: train_number.to_string(),
+            })
+            .await
+            .is_err());
+        assert_eq!(client.cache_len(), 0);
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
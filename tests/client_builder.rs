@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use mockito::{Matcher, Mock, ServerGuard};
+use reqwest::header::{HeaderMap, HeaderValue};
+use septa_api::Client;
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+#[tokio::test]
+async fn test_builder_headers_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .match_header("user-agent", "septa-api-test/1.0")
+        .match_header("x-api-key", "secret")
+        .with_body("[]")
+        .expect(2)
+        .create_async()
+        .await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-api-key", HeaderValue::from_static("secret"));
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .user_agent("septa-api-test/1.0")
+        .default_headers(headers)
+        .connect_timeout(Duration::from_secs(5))
+        .pool_max_idle_per_host(1)
+        .build()?;
+
+    // The same client, and clones of it, can be used for multiple requests
+    assert!(client.train_view().await?.is_empty());
+    assert!(client.clone().train_view().await?.is_empty());
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder_timeout_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .match_query(Matcher::Any)
+        .with_chunked_body(|writer| {
+            std::thread::sleep(Duration::from_millis(500));
+            writer.write_all(b"[]")
+        })
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .timeout(Duration::from_millis(50))
+        .build()?;
+
+    match client.train_view().await {
+        Err(septa_api::errors::Error::RequestFailed { source, .. }) => assert!(source.is_timeout()),
+        result => panic!("expected a timeout, got {:?}", result),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
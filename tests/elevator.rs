@@ -0,0 +1,165 @@
+use chrono::NaiveDate;
+use mockito::{Mock, ServerGuard};
+use septa_api::{types::RegionalRailStop, Client};
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+#[tokio::test]
+async fn test_elevator_outages_deserialize1_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/elevator/index.php")
+        .with_body(
+            r#"
+        {
+            "meta": {
+                "elevators_out": 2,
+                "updated": "2023-06-13 10:22:11"
+            },
+            "results": [
+                {
+                    "line": "Regional Rail",
+                    "station": "Suburban Station",
+                    "elevator": "Street to Concourse Elevator",
+                    "message": "No access to/from the concourse",
+                    "alternate_url": "https://www.septa.org/accessibility/alternate/suburban.html"
+                },
+                {
+                    "line": "Market-Frankford Line",
+                    "station": "Spring Garden Station",
+                    "elevator": "Westbound Platform Elevator",
+                    "message": "No access to/from the westbound platform",
+                    "alternate_url": ""
+                }
+            ]
+        }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client.elevator_outages().await?;
+
+    assert_eq!(response.meta.elevators_out, 2);
+    assert_eq!(
+        response.meta.updated,
+        NaiveDate::from_ymd_opt(2023, 6, 13)
+            .unwrap()
+            .and_hms_opt(10, 22, 11)
+            .unwrap()
+    );
+
+    assert_eq!(response.outages.len(), 2);
+    assert_eq!(response.outages[0].line, "Regional Rail");
+    assert_eq!(response.outages[0].station, "Suburban Station");
+    assert_eq!(response.outages[0].elevator, "Street to Concourse Elevator");
+    assert_eq!(
+        response.outages[0].message,
+        "No access to/from the concourse"
+    );
+    assert_eq!(
+        response.outages[0].alternate_url,
+        Some("https://www.septa.org/accessibility/alternate/suburban.html".to_string())
+    );
+    assert_eq!(
+        response.outages[0].rail_stop(),
+        Some(RegionalRailStop::SuburbanStation)
+    );
+
+    assert_eq!(response.outages[1].alternate_url, None);
+    assert_eq!(response.outages[1].rail_stop(), None);
+
+    assert_eq!(
+        response
+            .outages_at(&RegionalRailStop::SuburbanStation)
+            .len(),
+        1
+    );
+    assert!(response.has_outage_at(&RegionalRailStop::SuburbanStation));
+    assert!(!response.has_outage_at(&RegionalRailStop::JeffersonStation));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_elevator_outages_empty_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/elevator/index.php")
+        .with_body(
+            r#"
+        {
+            "meta": {
+                "elevators_out": 0,
+                "updated": "2023-06-14 03:00:02"
+            },
+            "results": []
+        }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client.elevator_outages().await?;
+
+    assert_eq!(response.meta.elevators_out, 0);
+    assert!(response.outages.is_empty());
+    assert!(!response.has_outage_at(&RegionalRailStop::SuburbanStation));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_elevator_outages_subway_station_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/elevator/index.php")
+        .with_body(
+            r#"
+        {
+            "meta": {
+                "elevators_out": 2,
+                "updated": "2023-06-15 08:41:37"
+            },
+            "results": [
+                {
+                    "line": "Market-Frankford Line",
+                    "station": "30th Street Station",
+                    "elevator": "Street to Eastbound Platform Elevator",
+                    "message": "No access to/from the eastbound platform",
+                    "alternate_url": ""
+                },
+                {
+                    "line": "Broad Street Line",
+                    "station": "Fern Rock Transit Center",
+                    "elevator": "Street to Platform Elevator",
+                    "message": "No access to/from the platform",
+                    "alternate_url": ""
+                }
+            ]
+        }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client.elevator_outages().await?;
+
+    assert_eq!(response.outages.len(), 2);
+    assert_eq!(response.outages[0].rail_stop(), None);
+    assert_eq!(response.outages[1].rail_stop(), None);
+
+    // The subway stations share their names with regional rail stops
+    assert!(response
+        .outages_at(&RegionalRailStop::Gray30thStreet)
+        .is_empty());
+    assert!(!response.has_outage_at(&RegionalRailStop::Gray30thStreet));
+    assert!(!response.has_outage_at(&RegionalRailStop::FernRockTC));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
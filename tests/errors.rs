@@ -0,0 +1,145 @@
+use mockito::{Mock, ServerGuard};
+use septa_api::{
+    errors::{Error, MAX_ERROR_BODY_LEN},
+    requests::RailScheduleRequest,
+    Client, Endpoint,
+};
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+#[tokio::test]
+async fn test_deserialize_error_context_async() -> Result<(), Error> {
+    let body = r#"
+[
+    {
+        "lat":"39.954174265",
+        "lon":"-75.16763361",
+        "trainno":"2333",
+        "service":"LOCAL",
+        "dest":"Wawa",
+        "currentstop":"Suburban Station",
+        "nextstop":"30th Street Station",
+        "line":"Media\/Wawa",
+        "consist":"872,871,858,857",
+        "heading":"189.8775840187919",
+        "late":0,
+        "SOURCE":"Norristown Transit Center",
+        "TRACK":"",
+        "TRACK_CHANGE":""
+    },
+    {
+        "lat":"not a latitude",
+        "lon":"-75.270441",
+        "trainno":"2530",
+        "service":"LOCAL",
+        "dest":"Lansdale",
+        "currentstop":"Gwynedd Valley",
+        "nextstop":"North Wales",
+        "line":"Lansdale\/Doylestown",
+        "consist":"415,366,367,126,125",
+        "heading":"326.98421204774684",
+        "late":0,
+        "SOURCE":"Newark",
+        "TRACK":"",
+        "TRACK_CHANGE":""
+    }
+]"#;
+
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(body)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+
+    match client.train_view().await {
+        Err(err @ Error::DeserializeFailed { .. }) => {
+            let context = err.context().expect("error should have a context");
+            assert_eq!(context.endpoint, Endpoint::TrainView);
+            assert!(context.params.is_empty());
+            assert_eq!(context.status.map(|status| status.as_u16()), Some(200));
+            assert_eq!(context.json_path.as_deref(), Some("[1].lat"));
+            assert_eq!(context.body.as_deref(), Some(body));
+
+            let message = err.to_string();
+            assert!(message.contains("endpoint: TrainView"));
+            assert!(message.contains("path: [1].lat"));
+        }
+        result => panic!("expected a deserialize error, got {:?}", result),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_request_error_context_async() -> Result<(), Error> {
+    let body = "<html><body>".to_string() + &"Service Unavailable ".repeat(100) + "</body></html>";
+
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/RRSchedules/index.php?req1=3236")
+        .with_status(503)
+        .with_body(&body)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let result = client
+        .rail_schedule(RailScheduleRequest {
+            train_number: "3236".to_string(),
+        })
+        .await;
+
+    match result {
+        Err(err @ Error::RequestFailed { .. }) => {
+            let context = err.context().expect("error should have a context");
+            assert_eq!(context.endpoint, Endpoint::RailSchedule);
+            assert_eq!(context.params, vec![("req1", "3236".to_string())]);
+            assert_eq!(context.status.map(|status| status.as_u16()), Some(503));
+            assert_eq!(context.json_path, None);
+
+            let context_body = context.body.as_deref().expect("body should be kept");
+            assert_eq!(context_body.len(), MAX_ERROR_BODY_LEN);
+            assert!(body.starts_with(context_body));
+
+            assert!(err.to_string().contains("params: req1=3236"));
+        }
+        result => panic!("expected a request error, got {:?}", result),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_api_error_context_async() -> Result<(), Error> {
+    let body = r#"[{"error": "TrainView has been disabled."}]"#;
+
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(body)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+
+    match client.train_view().await {
+        Err(Error::ApiErrorResponse { message, context }) => {
+            assert_eq!(message, "TrainView has been disabled.");
+
+            let context = context.expect("error should have a context");
+            assert_eq!(context.endpoint, Endpoint::TrainView);
+            assert_eq!(context.body.as_deref(), Some(body));
+        }
+        result => panic!("expected an API error, got {:?}", result),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
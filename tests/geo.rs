@@ -0,0 +1,93 @@
+use septa_api::{
+    geo::{bearing, haversine_distance, RegionalRailStopIndex},
+    types::RegionalRailStop,
+};
+use strum::IntoEnumIterator;
+
+// Philadelphia City Hall
+const CITY_HALL: (f64, f64) = (39.952335, -75.163789);
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "expected {} to be within {} of {}",
+        actual,
+        tolerance,
+        expected
+    );
+}
+
+#[test]
+fn test_haversine_distance_and_bearing() {
+    let suburban = RegionalRailStop::SuburbanStation.lat_lon().unwrap();
+    let gray_30th = RegionalRailStop::Gray30thStreet.lat_lon().unwrap();
+
+    assert_eq!(haversine_distance(suburban, suburban), 0.0);
+    assert_close(haversine_distance(suburban, gray_30th), 1222.8, 0.1);
+    assert_close(haversine_distance(gray_30th, suburban), 1222.8, 0.1);
+
+    assert_close(bearing(suburban, gray_30th), 284.6, 0.1);
+    assert_close(bearing((40.0, -75.0), (41.0, -75.0)), 0.0, 1e-9);
+    assert_close(bearing((40.0, -75.0), (40.0, -74.0)), 89.7, 0.1);
+    assert_close(bearing((41.0, -75.0), (40.0, -75.0)), 180.0, 1e-9);
+}
+
+#[test]
+fn test_nearest() {
+    let index = RegionalRailStopIndex::new();
+
+    let nearest = index.nearest(CITY_HALL.0, CITY_HALL.1, 3);
+    assert_eq!(
+        nearest
+            .iter()
+            .map(|nearby| nearby.stop.clone())
+            .collect::<Vec<RegionalRailStop>>(),
+        vec![
+            RegionalRailStop::SuburbanStation,
+            RegionalRailStop::JeffersonStation,
+            RegionalRailStop::Gray30thStreet
+        ]
+    );
+
+    assert_close(nearest[0].distance, 381.6, 0.1);
+    assert_close(nearest[0].bearing, 296.9, 0.1);
+    assert_close(nearest[1].distance, 488.7, 0.1);
+    assert_close(nearest[1].bearing, 87.8, 0.1);
+    assert_close(nearest[2].distance, 1597.7, 0.1);
+
+    assert!(index.nearest(CITY_HALL.0, CITY_HALL.1, 0).is_empty());
+
+    let known_stops = RegionalRailStop::iter()
+        .filter(|stop| !matches!(stop, RegionalRailStop::Unknown(_)))
+        .count();
+    let all = index.nearest(CITY_HALL.0, CITY_HALL.1, usize::MAX);
+    assert_eq!(all.len(), known_stops);
+    assert!(all
+        .windows(2)
+        .all(|pair| pair[0].distance <= pair[1].distance));
+}
+
+#[test]
+fn test_within() {
+    let index = RegionalRailStopIndex::new();
+
+    assert!(index.within(CITY_HALL.0, CITY_HALL.1, 300.0).is_empty());
+
+    let within = index.within(CITY_HALL.0, CITY_HALL.1, 500.0);
+    assert_eq!(
+        within
+            .iter()
+            .map(|nearby| nearby.stop.clone())
+            .collect::<Vec<RegionalRailStop>>(),
+        vec![
+            RegionalRailStop::SuburbanStation,
+            RegionalRailStop::JeffersonStation
+        ]
+    );
+
+    let (lat, lon) = RegionalRailStop::Wawa.lat_lon().unwrap();
+    let within = index.within(lat, lon, 0.0);
+    assert_eq!(within.len(), 1);
+    assert_eq!(within[0].stop, RegionalRailStop::Wawa);
+    assert_eq!(within[0].distance, 0.0);
+}
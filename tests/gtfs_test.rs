@@ -1,7 +1,15 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use once_cell::sync::Lazy;
-use septa_api::types::{RegionalRailStop, RegionalRailsLine};
+use septa_api::{
+    geo::RegionalRailStopIndex,
+    requests::{BusSchedulesRequest, Direction, Request},
+    types::{
+        BroadStreetStop, BusRoute, KnownRegionalRailStop, MarketFrankfordStop, NhslStop,
+        RegionalRailStop, RegionalRailsLine, TransportType,
+    },
+    Client,
+};
 use serde::{de::value::StrDeserializer, Deserialize};
 use strum::IntoEnumIterator;
 
@@ -16,6 +24,17 @@ static GTFS_DATA: Lazy<gtfs_structures::Gtfs> = Lazy::new(|| {
     .expect("Could not load GTFS data")
 });
 
+static GTFS_BUS_DATA: Lazy<gtfs_structures::Gtfs> = Lazy::new(|| {
+    gtfs_structures::Gtfs::new(
+        format!(
+            "{}/tests/gtfs_data/septa_bus.zip",
+            env!("CARGO_MANIFEST_DIR")
+        )
+        .as_str(),
+    )
+    .expect("Could not load GTFS data")
+});
+
 #[test]
 fn test_regional_rail_line_ids_test() -> Result<(), Box<dyn std::error::Error>> {
     let gtfs_rails = &GTFS_DATA;
@@ -36,6 +55,43 @@ fn test_regional_rail_line_ids_test() -> Result<(), Box<dyn std::error::Error>>
     Ok(())
 }
 
+#[test]
+fn test_bus_route_ids_test() -> Result<(), Box<dyn std::error::Error>> {
+    let gtfs_buses = &GTFS_BUS_DATA;
+
+    for route in gtfs_buses.routes.values() {
+        let route_name = route
+            .short_name
+            .as_ref()
+            .expect("GTFS route short name should be populated");
+        let bus_route = route_name.parse::<BusRoute>()?;
+
+        assert_eq!(bus_route.route_id(), route.id);
+        assert_eq!(route.id.parse::<BusRoute>()?, bus_route);
+
+        let transport_type = match route.route_type {
+            gtfs_structures::RouteType::Bus => TransportType::Bus,
+            gtfs_structures::RouteType::Tramway => TransportType::Trolley,
+            gtfs_structures::RouteType::Subway => TransportType::Subway,
+            route_type => panic!("unexpected route type {:?} for {}", route_type, route.id),
+        };
+
+        // The NHSL is listed as either a trolley or a subway depending on the feed
+        if bus_route.transport_type() == TransportType::Nhsl {
+            assert_ne!(transport_type, TransportType::Bus, "route {}", route.id);
+        } else {
+            assert_eq!(
+                bus_route.transport_type(),
+                transport_type,
+                "route {}",
+                route.id
+            );
+        }
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_regional_rail_name_test() -> Result<(), Box<dyn std::error::Error>> {
     let gtfs_rails = &GTFS_DATA;
@@ -153,6 +209,45 @@ fn test_regional_rail_stop_id_test() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_known_regional_rail_stop_data_test() -> Result<(), Box<dyn std::error::Error>> {
+    let gtfs_rails = &GTFS_DATA;
+
+    let gtfs_stops_by_id = gtfs_rails
+        .stops
+        .values()
+        .map(|stop| {
+            (
+                stop.id
+                    .parse::<u32>()
+                    .expect("GTFS stop id should be a u32"),
+                stop,
+            )
+        })
+        .collect::<BTreeMap<u32, _>>();
+
+    assert_eq!(
+        KnownRegionalRailStop::iter().count(),
+        gtfs_stops_by_id.len()
+    );
+
+    for stop in KnownRegionalRailStop::iter() {
+        let gtfs_stop = gtfs_stops_by_id
+            .get(&stop.stop_id())
+            .unwrap_or_else(|| panic!("{} should be in the GTFS data", stop));
+
+        assert_eq!(gtfs_stop.name.as_deref(), Some(stop.to_string().as_str()));
+        assert_eq!(
+            (gtfs_stop.latitude, gtfs_stop.longitude),
+            (Some(stop.lat_lon().0), Some(stop.lat_lon().1))
+        );
+        assert_eq!(RegionalRailStop::from(stop).stop_id()?, stop.stop_id());
+        assert_eq!(RegionalRailStop::from(stop).lat_lon()?, stop.lat_lon());
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_deserialize_regional_rail() -> Result<(), Box<dyn std::error::Error>> {
     let gtfs_rails = &GTFS_DATA;
@@ -168,3 +263,393 @@ fn test_deserialize_regional_rail() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_regional_rail_line_stops_test() -> Result<(), Box<dyn std::error::Error>> {
+    let gtfs_rails = &GTFS_DATA;
+
+    for line in RegionalRailsLine::iter() {
+        let line_stop_ids = line
+            .stops()
+            .iter()
+            .map(|stop| stop.stop_id().expect("stop id should be populated"))
+            .collect::<Vec<u32>>();
+
+        let mut visited_stop_ids = BTreeSet::new();
+
+        for trip in gtfs_rails
+            .trips
+            .values()
+            .filter(|trip| trip.route_id == line.id())
+        {
+            // Trains are through-routed across Center City so a trip can visit stops belonging to
+            // another line, only the stops of this line need to follow the ordering
+            let trip_positions = trip
+                .stop_times
+                .iter()
+                .filter_map(|stop_time| {
+                    let stop_id = stop_time
+                        .stop
+                        .id
+                        .parse::<u32>()
+                        .expect("GTFS stop id should be a u32");
+                    line_stop_ids.iter().position(|id| *id == stop_id)
+                })
+                .collect::<Vec<usize>>();
+
+            let ascending = trip_positions.windows(2).all(|pair| pair[0] < pair[1]);
+            let descending = trip_positions.windows(2).all(|pair| pair[0] > pair[1]);
+            assert!(
+                ascending || descending,
+                "trip {} visits the stops of {} out of order",
+                trip.id,
+                line
+            );
+
+            visited_stop_ids.extend(trip_positions.into_iter().map(|i| line_stop_ids[i]));
+        }
+
+        assert_eq!(
+            visited_stop_ids,
+            line_stop_ids.iter().copied().collect::<BTreeSet<u32>>(),
+            "stops of {} do not match the GTFS trips",
+            line
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_regional_rail_line_stops_in_direction_test() -> Result<(), Box<dyn std::error::Error>> {
+    for line in RegionalRailsLine::iter() {
+        let outbound = line.outbound_direction();
+        let inbound = match outbound {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+        };
+
+        assert_eq!(line.stops_in_direction(outbound), line.stops());
+
+        let mut inbound_stops = line.stops_in_direction(inbound);
+        inbound_stops.reverse();
+        assert_eq!(inbound_stops, line.stops());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_bus_schedules_stop_id_test() -> Result<(), Box<dyn std::error::Error>> {
+    let gtfs_buses = &GTFS_BUS_DATA;
+
+    // Every bus and trolley stop should be representable by a BusSchedulesRequest, and the stop
+    // id should be sent exactly as it appears in the GTFS data
+    for stop in gtfs_buses.stops.values() {
+        let stop_id = stop
+            .id
+            .parse::<u32>()
+            .expect("GTFS stop id should be a u32");
+
+        let params = BusSchedulesRequest {
+            stop_id,
+            route: None,
+            direction: None,
+            results: None,
+        }
+        .into_params();
+
+        assert_eq!(params, vec![("req1", stop.id.clone())]);
+    }
+
+    // The stops requested in tests/bus_schedules.rs and the BusSchedules doc example
+    for stop_id in [17842, 425] {
+        assert!(
+            gtfs_buses.stops.contains_key(&stop_id.to_string()),
+            "stop {} should be in the GTFS data",
+            stop_id
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_regional_rail_stop_index_test() -> Result<(), Box<dyn std::error::Error>> {
+    let gtfs_rails = &GTFS_DATA;
+    let index = RegionalRailStopIndex::new();
+
+    for stop in gtfs_rails.stops.values() {
+        let lat = stop.latitude.expect("GTFS latitude should be populated");
+        let lon = stop.longitude.expect("GTFS longitude should be populated");
+        let stop_id = stop
+            .id
+            .parse::<u32>()
+            .expect("GTFS stop id should be a u32");
+
+        let nearest = index.nearest(lat, lon, 1);
+        assert_eq!(nearest.len(), 1);
+        assert_eq!(nearest[0].distance, 0.0);
+
+        let within = index.within(lat, lon, 0.0);
+        assert!(
+            within
+                .iter()
+                .any(|nearby| nearby.stop.stop_id().ok() == Some(stop_id)),
+            "stop {} was not found at its own coordinates",
+            stop_id
+        );
+    }
+
+    Ok(())
+}
+
+/// The largest difference between a coordinate and the GTFS coordinate it was parsed from
+const COORDINATE_TOLERANCE: f64 = 1e-9;
+
+#[tokio::test]
+async fn test_route_stops_test() -> Result<(), Box<dyn std::error::Error>> {
+    let gtfs_buses = &GTFS_BUS_DATA;
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::with_base_url(server.url().as_str());
+
+    for route in gtfs_buses.routes.values() {
+        let route_name = route.short_name.clone().unwrap_or(route.id.clone());
+
+        // SEPTA lists the stops of the longest trip of the route
+        let Some(trip) = gtfs_buses
+            .trips
+            .values()
+            .filter(|trip| trip.route_id == route.id)
+            .max_by_key(|trip| (trip.stop_times.len(), trip.id.clone()))
+        else {
+            continue;
+        };
+
+        let mut stop_times = trip.stop_times.iter().collect::<Vec<_>>();
+        stop_times.sort_by_key(|stop_time| stop_time.stop_sequence);
+
+        let body = stop_times
+            .iter()
+            .map(|stop_time| {
+                serde_json::json!({
+                    "lng": stop_time.stop.longitude.expect("GTFS longitude should be populated").to_string(),
+                    "lat": stop_time.stop.latitude.expect("GTFS latitude should be populated").to_string(),
+                    "stopid": stop_time.stop.id,
+                    "stopname": stop_time.stop.name,
+                })
+            })
+            .collect::<Vec<serde_json::Value>>();
+
+        let mock = server
+            .mock("GET", "/Stops/index.php")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "req1".to_string(),
+                route_name.clone(),
+            ))
+            .with_body(serde_json::to_string(&body)?)
+            .create_async()
+            .await;
+
+        let stops = client.route_stops(route_name.parse::<BusRoute>()?).await?;
+        assert_eq!(stops.len(), stop_times.len(), "route {}", route_name);
+
+        for (index, (stop, stop_time)) in stops.iter().zip(stop_times).enumerate() {
+            assert_eq!(
+                stop.stop_id,
+                stop_time
+                    .stop
+                    .id
+                    .parse::<u32>()
+                    .expect("GTFS stop id should be a u32")
+            );
+            assert_eq!(Some(&stop.name), stop_time.stop.name.as_ref());
+
+            let lat = stop_time
+                .stop
+                .latitude
+                .expect("GTFS latitude should be populated");
+            let lon = stop_time
+                .stop
+                .longitude
+                .expect("GTFS longitude should be populated");
+            assert!(
+                (stop.lat - lat).abs() < COORDINATE_TOLERANCE,
+                "stop {} latitude {} should be {}",
+                stop.stop_id,
+                stop.lat,
+                lat
+            );
+            assert!(
+                (stop.lon - lon).abs() < COORDINATE_TOLERANCE,
+                "stop {} longitude {} should be {}",
+                stop.stop_id,
+                stop.lon,
+                lon
+            );
+
+            assert_eq!(stop.sequence, Some(index as u32 + 1));
+        }
+
+        mock.assert_async().await;
+        mock.remove_async().await;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_nhsl_stop_names_test() -> Result<(), Box<dyn std::error::Error>> {
+    let gtfs_buses = &GTFS_BUS_DATA;
+
+    let gtfs_stop_names = gtfs_buses
+        .stops
+        .values()
+        .filter_map(|stop| stop.name.as_deref())
+        .filter(|name| name.ends_with(" - NHSL"))
+        .collect::<BTreeSet<&str>>();
+
+    let enum_stop_names = NhslStop::iter()
+        .map(|stop| stop.to_string())
+        .collect::<BTreeSet<String>>();
+
+    assert_eq!(
+        gtfs_stop_names,
+        enum_stop_names
+            .iter()
+            .map(|name| name.as_str())
+            .collect::<BTreeSet<&str>>()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_nhsl_stop_order_test() -> Result<(), Box<dyn std::error::Error>> {
+    let gtfs_buses = &GTFS_BUS_DATA;
+    let stops = NhslStop::stops();
+    let mut visited_stops = BTreeSet::new();
+
+    for trip in gtfs_buses.trips.values() {
+        let trip_positions = trip
+            .stop_times
+            .iter()
+            .filter_map(|stop_time| {
+                let name = stop_time.stop.name.as_deref()?;
+                let stop = name.parse::<NhslStop>().ok()?;
+                stops.iter().position(|s| *s == stop)
+            })
+            .collect::<Vec<usize>>();
+
+        if trip_positions.is_empty() {
+            continue;
+        }
+
+        let ascending = trip_positions.windows(2).all(|pair| pair[0] < pair[1]);
+        let descending = trip_positions.windows(2).all(|pair| pair[0] > pair[1]);
+        assert!(
+            ascending || descending,
+            "trip {} visits the NHSL stops out of order",
+            trip.id
+        );
+
+        visited_stops.extend(trip_positions.into_iter().map(|i| stops[i].clone()));
+    }
+
+    assert_eq!(
+        visited_stops,
+        stops.iter().cloned().collect::<BTreeSet<NhslStop>>()
+    );
+
+    assert_eq!(NhslStop::stops_in_direction(Direction::South), stops);
+
+    let mut northbound_stops = NhslStop::stops_in_direction(Direction::North);
+    northbound_stops.reverse();
+    assert_eq!(northbound_stops, stops);
+
+    Ok(())
+}
+
+/// Asserts the GTFS stop names ending in `suffix` are exactly the display names of `T`
+fn assert_subway_stop_names<T: IntoEnumIterator + ToString>(suffix: &str) {
+    let gtfs_stop_names = GTFS_BUS_DATA
+        .stops
+        .values()
+        .filter_map(|stop| stop.name.clone())
+        .filter(|name| name.ends_with(suffix))
+        .collect::<BTreeSet<String>>();
+
+    let enum_stop_names = T::iter()
+        .map(|stop| stop.to_string())
+        .collect::<BTreeSet<String>>();
+
+    assert_eq!(gtfs_stop_names, enum_stop_names);
+}
+
+/// Asserts every GTFS trip visits the `stops` it serves in order, one way or the other, and
+/// returns the stops visited by any trip
+///
+/// Stops a trip serves that are not in `stops`, like the spur stops of a Broad Street Line trip
+/// checked against the main line, are skipped.
+fn visited_subway_stops<T: std::str::FromStr + Clone + Ord + PartialEq>(
+    stops: &[T],
+) -> BTreeSet<T> {
+    let mut visited_stops = BTreeSet::new();
+
+    for trip in GTFS_BUS_DATA.trips.values() {
+        let trip_positions = trip
+            .stop_times
+            .iter()
+            .filter_map(|stop_time| {
+                let name = stop_time.stop.name.as_deref()?;
+                let stop = name.parse::<T>().ok()?;
+                stops.iter().position(|s| *s == stop)
+            })
+            .collect::<Vec<usize>>();
+
+        if trip_positions.is_empty() {
+            continue;
+        }
+
+        let ascending = trip_positions.windows(2).all(|pair| pair[0] < pair[1]);
+        let descending = trip_positions.windows(2).all(|pair| pair[0] > pair[1]);
+        assert!(
+            ascending || descending,
+            "trip {} visits the subway stops out of order",
+            trip.id
+        );
+
+        visited_stops.extend(trip_positions.into_iter().map(|i| stops[i].clone()));
+    }
+
+    visited_stops
+}
+
+#[test]
+fn test_subway_stop_names_test() -> Result<(), Box<dyn std::error::Error>> {
+    assert_subway_stop_names::<MarketFrankfordStop>(" - MFL");
+    assert_subway_stop_names::<BroadStreetStop>(" - BSL");
+
+    Ok(())
+}
+
+#[test]
+fn test_subway_stop_order_test() -> Result<(), Box<dyn std::error::Error>> {
+    let market_frankford_stops = MarketFrankfordStop::stops();
+    assert_eq!(
+        visited_subway_stops(&market_frankford_stops),
+        market_frankford_stops
+            .iter()
+            .cloned()
+            .collect::<BTreeSet<MarketFrankfordStop>>()
+    );
+
+    let mut broad_street_stops = visited_subway_stops(&BroadStreetStop::stops());
+    broad_street_stops.extend(visited_subway_stops(&BroadStreetStop::ridge_spur_stops()));
+    assert_eq!(
+        broad_street_stops,
+        BroadStreetStop::iter().collect::<BTreeSet<BroadStreetStop>>()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,287 @@
+use mockito::{Mock, ServerGuard};
+use septa_api::{
+    requests::{ArrivalsRequest, Direction, NextToArriveRequest, RailScheduleRequest},
+    types::RegionalRailStop,
+    Client,
+};
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+#[tokio::test]
+async fn test_lenient_train_view_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(
+            r#"
+[
+    {"lat":"39.954174265","lon":"-75.16763361","trainno":"2333","service":"LOCAL","dest":"Wawa","currentstop":"Suburban Station","nextstop":"30th Street Station","line":"Media\/Wawa","consist":"872,871,858,857","heading":"189.8775840187919","late":0,"SOURCE":"Norristown Transit Center","TRACK":"","TRACK_CHANGE":""},
+    {"lat":"unknown","lon":"-75.270441","trainno":"2530","service":"LOCAL","dest":"Lansdale","currentstop":"Gwynedd Valley","nextstop":"North Wales","line":"Lansdale\/Doylestown","consist":"415,366,367,126,125","heading":"326.98421204774684","late":0,"SOURCE":"Newark","TRACK":"","TRACK_CHANGE":""},
+    {"lat":"39.953094545","lon":"-75.162311045","trainno":"3236","service":"LOCAL","dest":"Norristown Transit Center","currentstop":"Suburban Station","nextstop":"Jefferson Station","line":"Manayunk\/Norristown","consist":"705,716,861,862","heading":"101.50453615740082","late":0,"SOURCE":"Wawa","TRACK":"1A","TRACK_CHANGE":""}
+]"#,
+        )
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+
+    // A strict client fails the whole response
+    assert!(client.train_view().await.is_err());
+
+    let response = client.lenient().train_view().await?;
+
+    assert_eq!(response.records.len(), 2);
+    assert_eq!(response.records[0].train_number, "2333");
+    assert_eq!(response.records[1].train_number, "3236");
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(response.errors[0].index, 1);
+    assert_eq!(response.errors[0].direction, None);
+    assert_eq!(response.errors[0].raw["trainno"], "2530");
+    assert!(response.errors[0].reason.starts_with("lat: "));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lenient_arrivals_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server =
+        create_mock_server(&mut server, "/Arrivals/index.php?station=Temple+University")
+            .with_body(
+                r#"
+        {
+            "Temple U Departures: April 11, 2023, 6:29 pm": [
+              {
+                "Northbound": [
+                  {
+                    "direction": "N",
+                    "path": "R5/3N",
+                    "train_id": "5344",
+                    "origin": "Thorndale",
+                    "destination": "West Trenton",
+                    "line": "West Trenton",
+                    "status": "12 min",
+                    "service_type": "LOCAL",
+                    "next_station": "Suburban Station",
+                    "sched_time": "2023-04-11 18:30:00.000",
+                    "depart_time": "2023-04-11 18:30:00.000",
+                    "track": "2",
+                    "track_change": null,
+                    "platform": "",
+                    "platform_change": null
+                  },
+                  {
+                    "direction": "N",
+                    "path": "R3N",
+                    "train_id": "3236",
+                    "origin": "Elwyn",
+                    "destination": "West Trenton",
+                    "line": "West Trenton",
+                    "status": "On Time",
+                    "service_type": "LOCAL",
+                    "next_station": null,
+                    "sched_time": "2023-04-11 18:45:00.000",
+                    "depart_time": "not a time",
+                    "track": "2",
+                    "track_change": null,
+                    "platform": "",
+                    "platform_change": null
+                  }
+                ]
+              },
+              {
+                "Southbound": [
+                  {
+                    "direction": "S",
+                    "path": "R2S",
+                    "train_id": "9231",
+                    "origin": "Warminster",
+                    "destination": "Wilmington",
+                    "line": "Wilmington/Newark",
+                    "status": "On Time",
+                    "service_type": "EXPRESS",
+                    "next_station": null,
+                    "sched_time": "2023-04-11 18:33:00.000",
+                    "depart_time": "2023-04-11 18:33:00.000",
+                    "track": "1",
+                    "track_change": null,
+                    "platform": "",
+                    "platform_change": null
+                  },
+                  {
+                    "direction": "S",
+                    "path": "R3S",
+                    "train_id": "3237",
+                    "origin": "West Trenton",
+                    "destination": "Elwyn",
+                    "line": "Media/Wawa",
+                    "status": "On Time",
+                    "service_type": "LOCAL",
+                    "next_station": null,
+                    "sched_time": "not a time",
+                    "depart_time": "2023-04-11 18:40:00.000",
+                    "track": "1",
+                    "track_change": null,
+                    "platform": "",
+                    "platform_change": null
+                  }
+                ]
+              }
+            ]
+        }"#,
+            )
+            .create_async()
+            .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .lenient()
+        .arrivals(ArrivalsRequest {
+            station: RegionalRailStop::TempleUniversity,
+            results: None,
+            direction: None,
+        })
+        .await?;
+
+    assert_eq!(
+        response.records.title,
+        "Temple U Departures: April 11, 2023, 6:29 pm"
+    );
+    assert_eq!(response.records.northbound.len(), 1);
+    assert_eq!(response.records.southbound.len(), 1);
+    assert_eq!(response.records.southbound[0].train_id, "9231");
+
+    // Both lists have a bad record at index 1, the direction tells them apart
+    assert_eq!(response.errors.len(), 2);
+    assert_eq!(response.errors[0].index, 1);
+    assert_eq!(response.errors[0].direction, Some(Direction::North));
+    assert_eq!(response.errors[0].raw["train_id"], "3236");
+    assert!(response.errors[0].reason.starts_with("depart_time: "));
+    assert_eq!(response.errors[1].index, 1);
+    assert_eq!(response.errors[1].direction, Some(Direction::South));
+    assert_eq!(response.errors[1].raw["train_id"], "3237");
+    assert!(response.errors[1].reason.starts_with("sched_time: "));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lenient_next_to_arrive_and_rail_schedule_async(
+) -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let next_to_arrive_mock = create_mock_server(
+        &mut server,
+        "/NextToArrive/index.php?req1=Temple+University&req2=St.+Davids",
+    )
+    .with_body(
+        r#"
+    [
+        {
+            "orig_train": "2565",
+            "orig_line": "Paoli/Thorndale",
+            "orig_departure_time": "11:49PM",
+            "orig_arrival_time": "12:33AM",
+            "orig_delay": "On time",
+            "isdirect": "true"
+        },
+        {
+            "orig_train": "2567",
+            "orig_line": "Paoli/Thorndale",
+            "orig_departure_time": "11:59PM",
+            "orig_arrival_time": "12:43AM",
+            "orig_delay": "On time",
+            "isdirect": "maybe"
+        }
+    ]"#,
+    )
+    .create_async()
+    .await;
+    let rail_schedule_mock = create_mock_server(&mut server, "/RRSchedules/index.php?req1=3236")
+        .with_body(
+            r#"[
+    {
+        "station": "Wawa",
+        "sched_tm": "9:08 pm",
+        "est_tm": "9:09 pm",
+        "act_tm": "9:09 pm"
+    },
+    {
+        "station": "Wawa",
+        "sched_tm": "soon",
+        "est_tm": "9:12 pm",
+        "act_tm": "9:12 pm"
+    },
+    {
+        "station": "Elwyn Station",
+        "sched_tm": "9:13 pm",
+        "est_tm": "9:14 pm",
+        "act_tm": "9:14 pm"
+    }
+]"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+
+    let next_to_arrive = client
+        .lenient()
+        .next_to_arrive(NextToArriveRequest {
+            starting_station: RegionalRailStop::TempleUniversity,
+            ending_station: RegionalRailStop::StDavids,
+            results: None,
+        })
+        .await?;
+    assert_eq!(next_to_arrive.records.len(), 1);
+    assert_eq!(next_to_arrive.records[0].first_leg().train, "2565");
+    assert_eq!(next_to_arrive.errors.len(), 1);
+    assert_eq!(next_to_arrive.errors[0].index, 1);
+    assert!(next_to_arrive.errors[0].reason.starts_with("isdirect: "));
+
+    let rail_schedule = client
+        .lenient()
+        .rail_schedule(RailScheduleRequest {
+            train_number: "3236".to_string(),
+        })
+        .await?;
+    assert_eq!(rail_schedule.records.len(), 2);
+    assert_eq!(rail_schedule.records[1].station, RegionalRailStop::Elwyn);
+    assert_eq!(rail_schedule.errors.len(), 1);
+    assert_eq!(rail_schedule.errors[0].index, 1);
+    assert_eq!(rail_schedule.errors[0].raw["sched_tm"], "soon");
+    assert!(rail_schedule.errors[0].reason.starts_with("sched_tm: "));
+
+    next_to_arrive_mock.assert_async().await;
+    rail_schedule_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lenient_api_error_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(r#"[{"error": "TrainView has been disabled."}]"#)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+
+    match client.lenient().train_view().await {
+        Err(septa_api::errors::Error::ApiErrorResponse { message, .. }) => {
+            assert_eq!(message, "TrainView has been disabled.")
+        }
+        result => panic!("expected an API error, got {:?}", result),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use mockito::{Mock, ServerGuard};
+use septa_api::{
+    types::{LocationType, RegionalRailStop},
+    Client,
+};
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+#[tokio::test]
+async fn test_nearby_rail_stations_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(
+        &mut server,
+        "/locations/get_locations.php?lon=-75.1652&lat=39.9526&radius=0.5&type=rail_stations",
+    )
+    .with_body(
+        r#"
+        [
+            {
+                "location_id": "90005",
+                "location_name": "Suburban Station",
+                "location_lat": "39.9539",
+                "location_lon": "-75.1677",
+                "distance": "0.16",
+                "location_type": "rail_stations",
+                "location_data": {
+                    "address1": "16th St & JFK Blvd",
+                    "address2": null,
+                    "city": "Philadelphia",
+                    "state": "PA",
+                    "zip": "19102"
+                }
+            },
+            {
+                "location_id": "1234",
+                "location_name": "Jefferson",
+                "location_lat": "39.9525",
+                "location_lon": "-75.1581",
+                "distance": "0.37",
+                "location_type": "rail_stations",
+                "location_data": null
+            },
+            {
+                "location_id": "99999",
+                "location_name": "Not A Real Station",
+                "location_lat": "39.9501",
+                "location_lon": "-75.1601",
+                "distance": "0.45",
+                "location_type": "rail_stations",
+                "location_data": null
+            }
+        ]"#,
+    )
+    .create_async()
+    .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .nearby_locations(39.9526, -75.1652, 0.5, LocationType::RailStations)
+        .await?;
+
+    assert_eq!(response.len(), 3);
+
+    assert_eq!(response[0].id, "90005");
+    assert_eq!(response[0].name, "Suburban Station");
+    assert_eq!(response[0].lat, 39.9539);
+    assert_eq!(response[0].lon, -75.1677);
+    assert_eq!(response[0].distance, 0.16);
+    assert_eq!(response[0].location_type, LocationType::RailStations);
+    assert_eq!(response[0].data["city"], "Philadelphia");
+    assert_eq!(
+        response[0].rail_stop(),
+        Some(RegionalRailStop::SuburbanStation)
+    );
+
+    // The stop id is not a regional rail stop id so the stop is found by its name
+    assert_eq!(response[1].data, serde_json::Value::Null);
+    assert_eq!(
+        response[1].rail_stop(),
+        Some(RegionalRailStop::JeffersonStation)
+    );
+
+    assert_eq!(response[2].rail_stop(), None);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_nearby_bus_stops_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(
+        &mut server,
+        "/locations/get_locations.php?lon=-75.1652&lat=39.9526&radius=0.1&type=bus_stops",
+    )
+    .with_body(
+        r#"
+        [
+            {
+                "location_id": "90005",
+                "location_name": "Suburban Station",
+                "location_lat": "39.9536",
+                "location_lon": "-75.1660",
+                "distance": "0.08",
+                "location_type": "bus_stops",
+                "location_data": null
+            }
+        ]"#,
+    )
+    .create_async()
+    .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .nearby_locations(39.9526, -75.1652, 0.1, LocationType::BusStops)
+        .await?;
+
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0].location_type, LocationType::BusStops);
+
+    // Bus stops are never mapped to a regional rail stop, even when the names match
+    assert_eq!(response[0].rail_stop(), None);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_nearby_locations_empty_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(
+        &mut server,
+        "/locations/get_locations.php?lon=-75&lat=40&radius=1&type=sales_locations",
+    )
+    .with_body("[]")
+    .create_async()
+    .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .nearby_locations(40.0, -75.0, 1.0, LocationType::SalesLocations)
+        .await?;
+
+    assert!(response.is_empty());
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
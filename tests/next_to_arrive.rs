@@ -1,8 +1,9 @@
-use chrono::NaiveTime;
+use chrono::{Duration, NaiveDate, NaiveTime};
 use mockito::{Mock, ServerGuard};
 use septa_api::{
     requests::NextToArriveRequest,
-    types::{RegionalRailStop, RegionalRailsLine},
+    responses::{NextToArrive, NextToArriveLeg},
+    types::{RegionalRailStop, RegionalRailsLine, TrainStatus},
     Client,
 };
 
@@ -42,18 +43,226 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
     let response = client.next_to_arrive(next_to_arrive_request).await?;
 
     assert_eq!(response.len(), 1);
-    assert_eq!(response[0].orig_train, "2565");
-    assert_eq!(response[0].orig_line, RegionalRailsLine::PaoliThorndale);
     assert_eq!(
-        response[0].orig_departure_time,
-        NaiveTime::from_hms_opt(23, 49, 00).unwrap()
+        response[0],
+        NextToArrive::Direct(NextToArriveLeg {
+            train: "2565".to_string(),
+            line: RegionalRailsLine::PaoliThorndale,
+            departure_time: NaiveTime::from_hms_opt(23, 49, 00).unwrap(),
+            arrival_time: NaiveTime::from_hms_opt(0, 33, 00).unwrap(),
+            delay: TrainStatus::OnTime,
+        })
     );
+    assert!(response[0].is_direct());
+    assert_eq!(response[0].first_leg(), response[0].last_leg());
+
+    let service_date = NaiveDate::from_ymd_opt(2023, 6, 13).unwrap();
+    assert_eq!(
+        response[0].departure_date_time(service_date),
+        service_date.and_hms_opt(23, 49, 0).unwrap()
+    );
+    assert_eq!(
+        response[0].arrival_date_time(service_date),
+        NaiveDate::from_ymd_opt(2023, 6, 14)
+            .unwrap()
+            .and_hms_opt(0, 33, 0)
+            .unwrap()
+    );
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_with_connection_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(
+        &mut server,
+        "/NextToArrive/index.php?req1=Chestnut+Hill+West&req2=Norristown+Transit+Center",
+    )
+    .with_body(
+        r#"
+    [
+        {
+            "orig_train": "9246",
+            "orig_line": "Chestnut Hill West",
+            "orig_departure_time": "7:47PM",
+            "orig_arrival_time": "8:13PM",
+            "orig_delay": "3 min",
+            "term_train": "3260",
+            "term_line": "Manayunk/Norristown",
+            "term_depart_time": "8:21PM",
+            "term_arrival_time": "8:59PM",
+            "Connection": "Suburban Station",
+            "term_delay": "On time",
+            "isdirect": "false"
+        },
+        {
+            "orig_train": "9254",
+            "orig_line": "Chestnut Hill West",
+            "orig_departure_time": "11:17PM",
+            "orig_arrival_time": "11:43PM",
+            "orig_delay": "On time",
+            "term_train": "3272",
+            "term_line": "Manayunk/Norristown",
+            "term_depart_time": "12:05AM",
+            "term_arrival_time": "12:43AM",
+            "Connection": "Suburban Station",
+            "term_delay": "Suspended",
+            "isdirect": "false"
+        }
+    ]"#,
+    )
+    .create_async()
+    .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .next_to_arrive(NextToArriveRequest {
+            starting_station: RegionalRailStop::ChestnutHillWest,
+            ending_station: RegionalRailStop::NorristownTransitCenter,
+            results: None,
+        })
+        .await?;
+
+    assert_eq!(response.len(), 2);
+    assert_eq!(
+        response[0],
+        NextToArrive::WithConnection {
+            first_leg: NextToArriveLeg {
+                train: "9246".to_string(),
+                line: RegionalRailsLine::ChestnutHillWest,
+                departure_time: NaiveTime::from_hms_opt(19, 47, 00).unwrap(),
+                arrival_time: NaiveTime::from_hms_opt(20, 13, 00).unwrap(),
+                delay: TrainStatus::Late(Duration::minutes(3)),
+            },
+            connection: RegionalRailStop::SuburbanStation,
+            second_leg: NextToArriveLeg {
+                train: "3260".to_string(),
+                line: RegionalRailsLine::ManayunkNorristown,
+                departure_time: NaiveTime::from_hms_opt(20, 21, 00).unwrap(),
+                arrival_time: NaiveTime::from_hms_opt(20, 59, 00).unwrap(),
+                delay: TrainStatus::OnTime,
+            },
+        }
+    );
+    assert!(!response[0].is_direct());
+    assert_eq!(response[0].first_leg().train, "9246");
+    assert_eq!(response[0].last_leg().train, "3260");
+
+    // The connecting train departs after midnight
+    let service_date = NaiveDate::from_ymd_opt(2023, 6, 13).unwrap();
+    let next_day = NaiveDate::from_ymd_opt(2023, 6, 14).unwrap();
     assert_eq!(
-        response[0].orig_arrival_time,
-        NaiveTime::from_hms_opt(0, 33, 00).unwrap()
+        response[1].leg_date_times(service_date),
+        vec![
+            (
+                service_date.and_hms_opt(23, 17, 0).unwrap(),
+                service_date.and_hms_opt(23, 43, 0).unwrap()
+            ),
+            (
+                next_day.and_hms_opt(0, 5, 0).unwrap(),
+                next_day.and_hms_opt(0, 43, 0).unwrap()
+            )
+        ]
     );
-    assert_eq!(response[0].orig_delay, "On time");
-    assert!(response[0].is_direct);
+    assert_eq!(
+        response[1].arrival_date_time(service_date),
+        next_day.and_hms_opt(0, 43, 0).unwrap()
+    );
+    assert_eq!(response[1].last_leg().delay, TrainStatus::Suspended);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_with_connection_missing_fields_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(
+        &mut server,
+        "/NextToArrive/index.php?req1=Chestnut+Hill+West&req2=Norristown+Transit+Center",
+    )
+    .with_body(
+        r#"
+    [
+        {
+            "orig_train": "9246",
+            "orig_line": "Chestnut Hill West",
+            "orig_departure_time": "7:47PM",
+            "orig_arrival_time": "8:13PM",
+            "orig_delay": "On time",
+            "term_train": "3260",
+            "term_line": "Manayunk/Norristown",
+            "term_depart_time": "8:21PM",
+            "term_arrival_time": "8:59PM",
+            "term_delay": "On time",
+            "isdirect": "false"
+        }
+    ]"#,
+    )
+    .create_async()
+    .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let result = client
+        .next_to_arrive(NextToArriveRequest {
+            starting_station: RegionalRailStop::ChestnutHillWest,
+            ending_station: RegionalRailStop::NorristownTransitCenter,
+            results: None,
+        })
+        .await;
+
+    match result {
+        Err(septa_api::errors::Error::DeserializeFailed { source, .. }) => {
+            assert!(source
+                .to_string()
+                .contains("indirect trip is missing Connection"));
+        }
+        result => panic!("expected a deserialize error, got {:?}", result),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_suspended_deserialize_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(
+        &mut server,
+        "/NextToArrive/index.php?req1=Temple+University&req2=St.+Davids",
+    )
+    .with_body(
+        r#"
+    [
+        {
+            "orig_train": "2565",
+            "orig_line": "Paoli/Thorndale",
+            "orig_departure_time": "11:49PM",
+            "orig_arrival_time": "12:33AM",
+            "orig_delay": "Suspended",
+            "isdirect": "true"
+        }
+    ]"#,
+    )
+    .create_async()
+    .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .next_to_arrive(NextToArriveRequest {
+            starting_station: RegionalRailStop::TempleUniversity,
+            ending_station: RegionalRailStop::StDavids,
+            results: None,
+        })
+        .await?;
+
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0].first_leg().delay, TrainStatus::Suspended);
 
     mock_server.assert_async().await;
 
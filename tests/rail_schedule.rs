@@ -1,4 +1,4 @@
-use chrono::NaiveTime;
+use chrono::{Duration, NaiveDate, NaiveTime};
 use mockito::{Mock, ServerGuard};
 use septa_api::{requests::RailScheduleRequest, types::RegionalRailStop, Client};
 
@@ -324,3 +324,77 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_midnight_rollover_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/RRSchedules/index.php?req1=9284")
+        .with_body(
+            r#"[
+    {
+        "station": "Suburban Station",
+        "sched_tm": "11:50 pm",
+        "est_tm": "12:08 am",
+        "act_tm": "12:10 am"
+    },
+    {
+        "station": "Temple University",
+        "sched_tm": "11:58 pm",
+        "est_tm": "12:15 am",
+        "act_tm": "na"
+    },
+    {
+        "station": "Wayne Junction",
+        "sched_tm": "12:05 am",
+        "est_tm": "12:03 am",
+        "act_tm": "na"
+    }
+]"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .rail_schedule(RailScheduleRequest {
+            train_number: "9284".to_string(),
+        })
+        .await?;
+
+    let service_date = NaiveDate::from_ymd_opt(2023, 6, 13).unwrap();
+    let next_day = NaiveDate::from_ymd_opt(2023, 6, 14).unwrap();
+
+    // The train left 20 minutes late, after midnight
+    assert_eq!(
+        response[0].scheduled_date_time(service_date),
+        service_date.and_hms_opt(23, 50, 0).unwrap()
+    );
+    assert_eq!(
+        response[0].estimated_date_time(service_date),
+        next_day.and_hms_opt(0, 8, 0).unwrap()
+    );
+    assert_eq!(
+        response[0].actual_date_time(service_date),
+        Some(next_day.and_hms_opt(0, 10, 0).unwrap())
+    );
+    assert_eq!(response[0].delay(), Duration::minutes(20));
+
+    // Without an actual time the delay comes from the estimate
+    assert_eq!(response[1].actual_date_time(service_date), None);
+    assert_eq!(response[1].delay(), Duration::minutes(17));
+
+    // A stop scheduled after midnight belongs to the service day that started the evening before
+    assert_eq!(
+        response[2].scheduled_date_time(service_date),
+        next_day.and_hms_opt(0, 5, 0).unwrap()
+    );
+    assert_eq!(
+        response[2].estimated_date_time(service_date),
+        next_day.and_hms_opt(0, 3, 0).unwrap()
+    );
+    assert_eq!(response[2].delay(), Duration::minutes(-2));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use std::time::Duration;
+
+use mockito::{Mock, ServerGuard};
+use septa_api::{retry::RetryPolicy, Client, Endpoint};
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+fn fast_retry_policy(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy::new(max_attempts)
+        .with_initial_backoff(Duration::from_millis(1))
+        .with_max_backoff(Duration::from_millis(5))
+}
+
+#[test]
+fn test_retry_policy_backoff() {
+    let policy = RetryPolicy::new(10)
+        .with_initial_backoff(Duration::from_millis(100))
+        .with_max_backoff(Duration::from_secs(1))
+        .with_multiplier(2)
+        .with_jitter(false);
+
+    assert_eq!(policy.delay(1), Duration::from_millis(100));
+    assert_eq!(policy.delay(2), Duration::from_millis(200));
+    assert_eq!(policy.delay(3), Duration::from_millis(400));
+    assert_eq!(policy.delay(4), Duration::from_millis(800));
+    assert_eq!(policy.delay(5), Duration::from_secs(1));
+    assert_eq!(policy.delay(64), Duration::from_secs(1));
+
+    let policy = policy.with_jitter(true);
+    for attempt in 1..10 {
+        assert!(policy.delay(attempt) <= policy.max_delay(attempt));
+    }
+
+    assert_eq!(RetryPolicy::never().max_attempts(), 1);
+    assert_eq!(RetryPolicy::new(0).max_attempts(), 1);
+}
+
+#[tokio::test]
+async fn test_retry_server_error_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let failing_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_status(503)
+        .with_body("<html><body>Service Unavailable</body></html>")
+        .expect(2)
+        .create_async()
+        .await;
+    let success_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body("[]")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .retry_policy(fast_retry_policy(3))
+        .build()?;
+
+    assert!(client.train_view().await?.is_empty());
+
+    failing_mock.assert_async().await;
+    success_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_html_error_page_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let failing_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body("<html><body>Something went wrong</body></html>")
+        .expect(1)
+        .create_async()
+        .await;
+    let success_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body("[]")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .retry_policy(fast_retry_policy(3))
+        .build()?;
+
+    assert!(client.train_view().await?.is_empty());
+
+    failing_mock.assert_async().await;
+    success_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_gives_up_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let failing_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_status(500)
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .retry_policy(fast_retry_policy(3))
+        .build()?;
+
+    match client.train_view().await {
+        Err(septa_api::errors::Error::RequestFailed { source, .. }) => {
+            assert_eq!(source.status().map(|status| status.as_u16()), Some(500))
+        }
+        result => panic!("expected a server error, got {:?}", result),
+    }
+
+    failing_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_retry_client_error_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let failing_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_status(404)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .retry_policy(fast_retry_policy(3))
+        .build()?;
+
+    match client.train_view().await {
+        Err(err @ septa_api::errors::Error::RequestFailed { .. }) => assert!(!err.is_retryable()),
+        result => panic!("expected a client error, got {:?}", result),
+    }
+
+    failing_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_retry_api_error_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let failing_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(r#"[{"error": "TrainView has been disabled."}]"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .retry_policy(fast_retry_policy(3))
+        .build()?;
+
+    match client.train_view().await {
+        Err(septa_api::errors::Error::ApiErrorResponse { message, .. }) => {
+            assert_eq!(message, "TrainView has been disabled.")
+        }
+        result => panic!("expected an API error, got {:?}", result),
+    }
+
+    failing_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_endpoint_override_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let train_view_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_status(502)
+        .expect(1)
+        .create_async()
+        .await;
+    let transit_view_all_mock = create_mock_server(&mut server, "/TransitViewAll/index.php")
+        .with_status(502)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .with_base_url(server.url().as_str())
+        .retry_policy(fast_retry_policy(2))
+        .endpoint_retry_policy(Endpoint::TrainView, RetryPolicy::never())
+        .build()?;
+
+    assert!(client.train_view().await.is_err());
+    assert!(client.transit_view_all().await.is_err());
+
+    train_view_mock.assert_async().await;
+    transit_view_all_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_retry_by_default_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let failing_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_status(500)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    assert!(client.train_view().await.is_err());
+
+    failing_mock.assert_async().await;
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use mockito::{Mock, ServerGuard};
+use septa_api::{types::BusRoute, Client};
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+#[tokio::test]
+async fn test_route_stops_deserialize1_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/Stops/index.php?req1=17")
+        .with_body(
+            r#"
+        [
+            {
+                "lng": "-75.162393",
+                "lat": "39.952286",
+                "stopid": "10266",
+                "stopname": "Juniper St & Market St"
+            },
+            {
+                "lng": "-75.162599",
+                "lat": "39.950812",
+                "stopid": "17842",
+                "stopname": "Juniper St & Chestnut St"
+            },
+            {
+                "lng": -75.162808,
+                "lat": 39.949351,
+                "stopid": 425,
+                "stopname": "Juniper St & Walnut St"
+            }
+        ]"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client.route_stops("17".parse::<BusRoute>()?).await?;
+
+    assert_eq!(response.len(), 3);
+
+    assert_eq!(response[0].stop_id, 10266);
+    assert_eq!(response[0].name, "Juniper St & Market St");
+    assert_eq!(response[0].lat, 39.952286);
+    assert_eq!(response[0].lon, -75.162393);
+    assert_eq!(response[0].sequence, Some(1));
+
+    assert_eq!(response[1].stop_id, 17842);
+    assert_eq!(response[1].sequence, Some(2));
+
+    assert_eq!(response[2].stop_id, 425);
+    assert_eq!(response[2].lat, 39.949351);
+    assert_eq!(response[2].lon, -75.162808);
+    assert_eq!(response[2].sequence, Some(3));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_route_stops_invalid_stop_id_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/Stops/index.php?req1=17")
+        .with_body(
+            r#"
+        [
+            {
+                "lng": "-75.162393",
+                "lat": "39.952286",
+                "stopid": "-1",
+                "stopname": "Juniper St & Market St"
+            }
+        ]"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+
+    match client.route_stops("17".parse::<BusRoute>()?).await {
+        Err(septa_api::errors::Error::DeserializeFailed { context, .. }) => {
+            let context = context.expect("error should have a context");
+            assert_eq!(context.json_path.as_deref(), Some("[0].stopid"));
+        }
+        result => panic!("expected a deserialize error, got {:?}", result),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_route_stops_api_error_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/Stops/index.php?req1=999")
+        .with_body(r#"[{"error": "Route not found"}]"#)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+
+    match client.route_stops("999".parse::<BusRoute>()?).await {
+        Err(septa_api::errors::Error::ApiErrorResponse { message, .. }) => {
+            assert_eq!(message, "Route not found")
+        }
+        result => panic!("expected an API error, got {:?}", result),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
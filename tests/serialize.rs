@@ -0,0 +1,710 @@
+use septa_api::{
+    normalized::{self, FromNormalized},
+    responses::{
+        AlertSummary, AlertsResponse, ArrivalsResponse, BusDetoursResponse, BusSchedulesResponse,
+        ElevatorOutagesResponse, LocationsResponse, NextToArriveResponse, RailScheduleResponse,
+        RouteStopsResponse, TrainResponse, TransitViewAllResponse, TransitViewResponse,
+    },
+    types::{
+        AlertRoute, BusRoute, NhslStop, RegionalRailStop, RegionalRailsLine, ServiceType,
+        TransportType,
+    },
+};
+use serde::Serialize;
+use serde_json::json;
+use std::fmt::Debug;
+use strum::IntoEnumIterator;
+
+/// Serializes the value, deserializes it back through the normalized format and checks that it is
+/// the same value
+fn round_trip<T: Serialize + FromNormalized + PartialEq + Debug>(value: &T) -> serde_json::Value {
+    let serialized = serde_json::to_string(value).expect("value should serialize");
+    let deserialized: T =
+        normalized::from_str(&serialized).expect("serialized value should deserialize");
+
+    assert_eq!(&deserialized, value);
+
+    serde_json::to_value(value).expect("value should serialize")
+}
+
+#[test]
+fn test_serialize_train() {
+    let trains: TrainResponse = serde_json::from_str(
+        r#"
+[
+    {
+        "lat":"39.954174265",
+        "lon":"-75.16763361",
+        "trainno":"2333",
+        "service":"LOCAL",
+        "dest":"Wawa",
+        "currentstop":"Suburban Station",
+        "nextstop":"30th Street Station",
+        "line":"Media\/Wawa",
+        "consist":"872,871,858,857",
+        "heading":"189.8775840187919",
+        "late":0,
+        "SOURCE":"Not A Real Station",
+        "TRACK":"",
+        "TRACK_CHANGE":""
+    }
+]"#,
+    )
+    .expect("train view should deserialize");
+
+    let serialized = round_trip(&trains);
+
+    // The serialized form is not a SEPTA response
+    assert!(serde_json::from_value::<TrainResponse>(serialized.clone()).is_err());
+
+    assert_eq!(
+        serialized,
+        json!([{
+            "lat": 39.954174265,
+            "lon": -75.16763361,
+            "train_number": "2333",
+            "service": "LOCAL",
+            "dest": {"name": "Wawa", "stop_id": 90300},
+            "current_stop": {"name": "Suburban Station", "stop_id": 90005},
+            "next_stop": {"name": "Gray 30th St Station", "stop_id": 90004},
+            "line": "Media/Wawa",
+            "consist": [872, 871, 858, 857],
+            "heading": 189.8775840187919,
+            "late": 0,
+            "source": {"name": "Not A Real Station", "stop_id": null},
+            "track": null,
+            "track_change": null
+        }])
+    );
+}
+
+#[test]
+fn test_serialize_arrivals() {
+    let arrivals: ArrivalsResponse = serde_json::from_str(
+        r#"
+        {
+            "Temple U Departures: April 11, 2023, 6:29 pm": [
+              {
+                "Northbound": [
+                  {
+                    "direction": "N",
+                    "path": "R5/3N",
+                    "train_id": "5344",
+                    "origin": "Thorndale",
+                    "destination": "West Trenton",
+                    "line": "West Trenton",
+                    "status": "12 min",
+                    "service_type": "LOCAL",
+                    "next_station": "Suburban Station",
+                    "sched_time": "2023-04-11 18:30:00.000",
+                    "depart_time": "2023-04-11 18:42:00.000",
+                    "track": "2",
+                    "track_change": null,
+                    "platform": "",
+                    "platform_change": null
+                  }
+                ]
+              },
+              {
+                "Southbound": []
+              }
+            ]
+        }"#,
+    )
+    .expect("arrivals should deserialize");
+
+    let serialized = round_trip(&arrivals);
+
+    assert_eq!(
+        serialized["title"],
+        "Temple U Departures: April 11, 2023, 6:29 pm"
+    );
+    assert_eq!(serialized["southbound"], json!([]));
+
+    let arrival = &serialized["northbound"][0];
+    assert_eq!(arrival["origin"]["name"], "Thorndale");
+    assert_eq!(arrival["line"], "West Trenton");
+    assert_eq!(arrival["next_station"]["stop_id"], 90005);
+    assert_eq!(arrival["sched_time"], "2023-04-11T18:30:00");
+    assert_eq!(arrival["depart_time"], "2023-04-11T18:42:00");
+    assert_eq!(arrival["track_change"], json!(null));
+}
+
+#[test]
+fn test_serialize_next_to_arrive_and_rail_schedule() {
+    let next_to_arrive: NextToArriveResponse = serde_json::from_str(
+        r#"
+    [
+        {
+            "orig_train": "2565",
+            "orig_line": "Paoli/Thorndale",
+            "orig_departure_time": "11:49PM",
+            "orig_arrival_time": "12:33AM",
+            "orig_delay": "On time",
+            "isdirect": "true"
+        }
+    ]"#,
+    )
+    .expect("next to arrive should deserialize");
+
+    assert_eq!(
+        round_trip(&next_to_arrive),
+        json!([{
+            "type": "direct",
+            "train": "2565",
+            "line": "Paoli/Thorndale",
+            "departure_time": "23:49:00",
+            "arrival_time": "00:33:00",
+            "delay": "On Time"
+        }])
+    );
+
+    let with_connection: NextToArriveResponse = serde_json::from_str(
+        r#"
+    [
+        {
+            "orig_train": "9246",
+            "orig_line": "Chestnut Hill West",
+            "orig_departure_time": "7:47PM",
+            "orig_arrival_time": "8:13PM",
+            "orig_delay": "3 min",
+            "term_train": "3260",
+            "term_line": "Manayunk/Norristown",
+            "term_depart_time": "8:21PM",
+            "term_arrival_time": "8:59PM",
+            "Connection": "Suburban Station",
+            "term_delay": "On time",
+            "isdirect": "false"
+        }
+    ]"#,
+    )
+    .expect("next to arrive with a connection should deserialize");
+
+    assert_eq!(
+        round_trip(&with_connection),
+        json!([{
+            "type": "with_connection",
+            "first_leg": {
+                "train": "9246",
+                "line": "Chestnut Hill West",
+                "departure_time": "19:47:00",
+                "arrival_time": "20:13:00",
+                "delay": "3 min"
+            },
+            "connection": {"name": "Suburban Station", "stop_id": 90005},
+            "second_leg": {
+                "train": "3260",
+                "line": "Manayunk/Norristown",
+                "departure_time": "20:21:00",
+                "arrival_time": "20:59:00",
+                "delay": "On Time"
+            }
+        }])
+    );
+
+    let rail_schedule: RailScheduleResponse = serde_json::from_str(
+        r#"[
+    {
+        "station": "Wawa",
+        "sched_tm": "9:08 pm",
+        "est_tm": "9:09 pm",
+        "act_tm": "9:09 pm"
+    },
+    {
+        "station": "Elwyn Station",
+        "sched_tm": "9:13 pm",
+        "est_tm": "9:14 pm",
+        "act_tm": "na"
+    }
+]"#,
+    )
+    .expect("rail schedule should deserialize");
+
+    assert_eq!(
+        round_trip(&rail_schedule),
+        json!([
+            {
+                "station": {"name": "Wawa", "stop_id": 90300},
+                "scheduled_time": "21:08:00",
+                "estimated_time": "21:09:00",
+                "actual_time": "21:09:00"
+            },
+            {
+                "station": {"name": "Elwyn", "stop_id": 90301},
+                "scheduled_time": "21:13:00",
+                "estimated_time": "21:14:00",
+                "actual_time": null
+            }
+        ])
+    );
+}
+
+#[test]
+fn test_serialize_transit_view_all() {
+    let transit_view_all: TransitViewAllResponse = serde_json::from_str(
+        r#"
+        {
+            "routes": [
+                {
+                    "10": [
+                        {
+                            "lat": "39.962364",
+                            "lng": "-75.225998",
+                            "label": "9068",
+                            "VehicleID": "9068",
+                            "BlockID": "9022",
+                            "Direction": "WestBound",
+                            "destination": "63rd-Malvern",
+                            "trip": "231450",
+                            "heading": "270.5",
+                            "Offset": "1",
+                            "Offset_sec": "70",
+                            "late": 2
+                        }
+                    ],
+                    "LUCYGO": []
+                }
+            ]
+        }"#,
+    )
+    .expect("transit view all should deserialize");
+
+    let serialized = round_trip(&transit_view_all);
+
+    assert_eq!(serialized["routes"]["LUCYGO"], json!([]));
+    assert_eq!(
+        serialized["routes"]["10"][0],
+        json!({
+            "lat": 39.962364,
+            "lon": -75.225998,
+            "label": "9068",
+            "vehicle_id": "9068",
+            "block_id": "9022",
+            "direction": "WestBound",
+            "destination": "63rd-Malvern",
+            "trip": "231450",
+            "heading": 270.5,
+            "offset": 1,
+            "late": 2,
+            "next_stop_name": null
+        })
+    );
+}
+
+#[test]
+fn test_serialize_transit_view() {
+    let transit_view: TransitViewResponse = serde_json::from_str(
+        r#"
+        {
+            "bus": [
+                {
+                    "lat": "39.930321",
+                    "lng": "-75.174851",
+                    "label": "3216",
+                    "VehicleID": "3216",
+                    "BlockID": "8162",
+                    "Direction": "NorthBound",
+                    "destination": null,
+                    "trip": null,
+                    "heading": null,
+                    "Offset": "0",
+                    "Offset_sec": "12",
+                    "late": 0
+                }
+            ]
+        }"#,
+    )
+    .expect("transit view should deserialize");
+
+    let serialized = round_trip(&transit_view);
+
+    assert_eq!(serialized["vehicles"][0]["heading"], json!(null));
+    assert_eq!(serialized["vehicles"][0]["destination"], json!(null));
+}
+
+#[test]
+fn test_serialize_alerts() {
+    let alerts_summary: Vec<AlertSummary> = serde_json::from_str(
+        r#"
+        [
+            {
+                "route_id": "rr_route_pao",
+                "route_name": "Paoli/Thorndale",
+                "mode": "Regional Rail",
+                "isadvisory": "Yes",
+                "isdetour": "No",
+                "isalert": "No",
+                "issuppend": "No",
+                "last_updated": "Jun 13 2023 10:23:12:000AM",
+                "isSnow": "N"
+            }
+        ]"#,
+    )
+    .expect("alerts summary should deserialize");
+
+    assert_eq!(
+        round_trip(&alerts_summary),
+        json!([{
+            "route": "rr_route_pao",
+            "route_name": "Paoli/Thorndale",
+            "mode": "Regional Rail",
+            "is_advisory": true,
+            "is_detour": false,
+            "is_alert": false,
+            "is_suspended": false,
+            "last_updated": "2023-06-13T10:23:12",
+            "is_snow": false
+        }])
+    );
+
+    let alerts: AlertsResponse = serde_json::from_str(
+        r#"
+        [
+            {
+                "route_id": "bus_route_17",
+                "route_name": "17",
+                "current_message": "Expect delays due to a disabled vehicle",
+                "advisory_message": "",
+                "detour_message": "Detoured via 19th St",
+                "detour_id": "4123",
+                "detour_start_location": "20th & Johnston",
+                "detour_start_date_time": "6/13/2023   9:00 AM",
+                "detour_end_date_time": "6/14/2023   5:00 PM",
+                "detour_reason": "Construction",
+                "last_updated": "Jun 13 2023 09:01:44:000AM",
+                "isSnow": "N"
+            },
+            {
+                "route_id": "generic",
+                "route_name": "Generic",
+                "current_message": "",
+                "advisory_message": "",
+                "detour_message": "",
+                "detour_id": "",
+                "detour_start_location": "",
+                "detour_start_date_time": "",
+                "detour_end_date_time": "",
+                "detour_reason": "",
+                "last_updated": "",
+                "isSnow": "N"
+            }
+        ]"#,
+    )
+    .expect("alerts should deserialize");
+
+    assert_eq!(
+        round_trip(&alerts),
+        json!([
+            {
+                "route": "bus_route_17",
+                "route_name": "17",
+                "current_message": "Expect delays due to a disabled vehicle",
+                "advisory_message": null,
+                "detour": {
+                    "id": "4123",
+                    "message": "Detoured via 19th St",
+                    "start_location": "20th & Johnston",
+                    "start_date_time": "2023-06-13T09:00:00",
+                    "end_date_time": "2023-06-14T17:00:00",
+                    "reason": "Construction"
+                },
+                "last_updated": "2023-06-13T09:01:44",
+                "is_snow": false
+            },
+            {
+                "route": "generic",
+                "route_name": "Generic",
+                "current_message": null,
+                "advisory_message": null,
+                "detour": null,
+                "last_updated": null,
+                "is_snow": false
+            }
+        ])
+    );
+}
+
+#[test]
+fn test_serialize_bus_schedules() {
+    let bus_schedules: BusSchedulesResponse = serde_json::from_str(
+        r#"
+        {
+            "17": [
+                {
+                    "StopName": "Juniper St & Chestnut St",
+                    "Route": "17",
+                    "date": "4:21p",
+                    "day": "Tue",
+                    "Direction": "1",
+                    "DateCalender": "04/25/23 04:21 pm",
+                    "DirectionDesc": "Front-Market"
+                }
+            ]
+        }"#,
+    )
+    .expect("bus schedules should deserialize");
+
+    let serialized = round_trip(&bus_schedules);
+
+    assert_eq!(
+        serialized["17"][0],
+        json!({
+            "stop_name": "Juniper St & Chestnut St",
+            "route": "17",
+            "day": "Tue",
+            "direction": "1",
+            "direction_description": "Front-Market",
+            "scheduled_time": "2023-04-25T16:21:00"
+        })
+    );
+}
+
+#[test]
+fn test_serialize_locations() {
+    let locations: LocationsResponse = serde_json::from_str(
+        r#"
+        [
+            {
+                "location_id": "90005",
+                "location_name": "Suburban Station",
+                "location_lat": "39.9539",
+                "location_lon": "-75.1677",
+                "distance": "0.16",
+                "location_type": "rail_stations",
+                "location_data": {
+                    "address1": "16th St & JFK Blvd",
+                    "city": "Philadelphia"
+                }
+            }
+        ]"#,
+    )
+    .expect("locations should deserialize");
+
+    let serialized = round_trip(&locations);
+
+    assert_eq!(serialized[0]["lat"], json!(39.9539));
+    assert_eq!(serialized[0]["location_type"], json!("rail_stations"));
+}
+
+#[test]
+fn test_serialize_elevator_outages() {
+    let elevator_outages: ElevatorOutagesResponse = serde_json::from_str(
+        r#"
+        {
+            "meta": {
+                "elevators_out": 1,
+                "updated": "2023-06-13 10:22:11"
+            },
+            "results": [
+                {
+                    "line": "Regional Rail",
+                    "station": "Suburban Station",
+                    "elevator": "Street to Concourse Elevator",
+                    "message": "No access to/from the concourse",
+                    "alternate_url": ""
+                }
+            ]
+        }"#,
+    )
+    .expect("elevator outages should deserialize");
+
+    let serialized = round_trip(&elevator_outages);
+
+    assert_eq!(serialized["meta"]["updated"], json!("2023-06-13T10:22:11"));
+    assert_eq!(serialized["outages"][0]["alternate_url"], json!(null));
+}
+
+#[test]
+fn test_serialize_bus_detours() {
+    let bus_detours: BusDetoursResponse = serde_json::from_str(
+        r#"
+        [
+            {
+                "route_id": "17",
+                "route_info": [
+                    {
+                        "route_direction": "SB",
+                        "reason": "Police Activity",
+                        "start_location": "Juniper & Walnut",
+                        "end_location": "",
+                        "start_date_time": "12/1/2023 11:45 PM",
+                        "end_date_time": "",
+                        "current_message": ""
+                    }
+                ]
+            }
+        ]"#,
+    )
+    .expect("bus detours should deserialize");
+
+    let serialized = round_trip(&bus_detours);
+
+    assert_eq!(serialized[0]["route_id"], json!("17"));
+    assert_eq!(
+        serialized[0]["detours"][0]["start_date_time"],
+        json!("2023-12-01T23:45:00")
+    );
+    assert_eq!(serialized[0]["detours"][0]["end_date_time"], json!(null));
+}
+
+#[test]
+fn test_serialize_route_stops() {
+    let route_stops: RouteStopsResponse = serde_json::from_str(
+        r#"
+        [
+            {
+                "lng": "-75.162393",
+                "lat": "39.952286",
+                "stopid": "10266",
+                "stopname": "Juniper St & Market St"
+            }
+        ]"#,
+    )
+    .expect("route stops should deserialize");
+
+    let serialized = round_trip(&route_stops);
+
+    assert_eq!(
+        serialized[0],
+        json!({
+            "stop_id": 10266,
+            "name": "Juniper St & Market St",
+            "lat": 39.952286,
+            "lon": -75.162393,
+            "sequence": null
+        })
+    );
+}
+
+#[test]
+fn test_serialize_types() {
+    for stop in RegionalRailStop::iter() {
+        if let RegionalRailStop::Unknown(_) = stop {
+            continue;
+        }
+
+        let serialized = serde_json::to_value(&stop).expect("stop should serialize");
+        assert_eq!(
+            serialized,
+            json!({"name": stop.to_string(), "stop_id": stop.stop_id().ok()})
+        );
+        assert_eq!(
+            normalized::from_value::<RegionalRailStop>(serialized).ok(),
+            Some(stop)
+        );
+    }
+
+    for line in RegionalRailsLine::iter() {
+        let serialized = serde_json::to_value(&line).expect("line should serialize");
+        assert_eq!(serialized, json!(line.to_string()));
+        assert_eq!(
+            normalized::from_value::<RegionalRailsLine>(serialized).ok(),
+            Some(line)
+        );
+    }
+
+    for transport_type in TransportType::iter() {
+        let serialized = serde_json::to_value(&transport_type).expect("type should serialize");
+        assert_eq!(
+            normalized::from_value::<TransportType>(serialized).ok(),
+            Some(transport_type)
+        );
+    }
+
+    for stop in NhslStop::iter() {
+        let serialized = serde_json::to_value(&stop).expect("stop should serialize");
+        assert_eq!(serialized, json!(stop.to_string()));
+        assert_eq!(
+            normalized::from_value::<NhslStop>(serialized).ok(),
+            Some(stop)
+        );
+    }
+
+    for service_type in [
+        ServiceType::Express,
+        ServiceType::Local,
+        ServiceType::Unknown("WEEKEND".to_string()),
+    ] {
+        let serialized = serde_json::to_value(&service_type).expect("type should serialize");
+        assert_eq!(
+            normalized::from_value::<ServiceType>(serialized).ok(),
+            Some(service_type)
+        );
+    }
+
+    for route in [
+        AlertRoute::Generic,
+        AlertRoute::RegionalRail(RegionalRailsLine::Cynwyd),
+        AlertRoute::Bus("17".parse().unwrap()),
+        AlertRoute::Trolley("10".parse().unwrap()),
+        AlertRoute::Other("rr_route_gc".to_string()),
+    ] {
+        let serialized = serde_json::to_value(&route).expect("route should serialize");
+        assert_eq!(
+            normalized::from_value::<AlertRoute>(serialized.clone()).ok(),
+            Some(route.clone())
+        );
+        assert_eq!(
+            serde_json::from_value::<AlertRoute>(serialized).ok(),
+            Some(route)
+        );
+    }
+
+    for route in ["17", "G1", "BLVDDIR", "10", "101", "LUCYGO"] {
+        let route = route.parse::<BusRoute>().expect("route should parse");
+        let serialized = serde_json::to_value(&route).expect("route should serialize");
+        assert_eq!(serialized, json!(route.route_id()));
+        assert_eq!(
+            serde_json::from_value::<BusRoute>(serialized).ok(),
+            Some(route)
+        );
+    }
+
+    assert!(serde_json::from_value::<BusRoute>(json!("")).is_err());
+    assert!(serde_json::from_value::<BusRoute>(json!("bus_route_17")).is_err());
+}
+
+#[test]
+fn test_deserialize_display_names() {
+    // The enums deserialize from the same display name that they serialize to
+    for line in RegionalRailsLine::iter() {
+        let serialized = serde_json::to_value(&line).expect("line should serialize");
+        assert_eq!(
+            serde_json::from_value::<RegionalRailsLine>(serialized).ok(),
+            Some(line)
+        );
+    }
+
+    for transport_type in TransportType::iter() {
+        let serialized = serde_json::to_value(&transport_type).expect("type should serialize");
+        assert_eq!(
+            serde_json::from_value::<TransportType>(serialized).ok(),
+            Some(transport_type)
+        );
+    }
+
+    for stop in NhslStop::iter() {
+        let serialized = serde_json::to_value(&stop).expect("stop should serialize");
+        assert_eq!(
+            serde_json::from_value::<NhslStop>(serialized).ok(),
+            Some(stop)
+        );
+    }
+
+    for service_type in [
+        ServiceType::Express,
+        ServiceType::Local,
+        ServiceType::Unknown("WEEKEND".to_string()),
+    ] {
+        let serialized = serde_json::to_value(&service_type).expect("type should serialize");
+        assert_eq!(
+            serde_json::from_value::<ServiceType>(serialized).ok(),
+            Some(service_type)
+        );
+    }
+
+    assert_eq!(
+        serde_json::from_value::<RegionalRailsLine>(json!("Media/Wawa")).ok(),
+        Some(RegionalRailsLine::MediaWawa)
+    );
+}
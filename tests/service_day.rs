@@ -0,0 +1,54 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use septa_api::service_day::{anchor, resolve_near, SERVICE_DAY_START};
+
+fn time(hour: u32, min: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, min, 0).unwrap()
+}
+
+fn date_time(day: u32, hour: u32, min: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2023, 12, day)
+        .unwrap()
+        .and_time(time(hour, min))
+}
+
+#[test]
+fn test_anchor() {
+    let service_date = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+
+    assert_eq!(anchor(service_date, SERVICE_DAY_START), date_time(31, 3, 0));
+    assert_eq!(anchor(service_date, time(23, 59)), date_time(31, 23, 59));
+
+    // Post-midnight times roll over onto the next year
+    assert_eq!(
+        anchor(service_date, time(0, 0)),
+        NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_time(time(0, 0))
+    );
+    assert_eq!(
+        anchor(service_date, time(2, 59)),
+        NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_time(time(2, 59))
+    );
+}
+
+#[test]
+fn test_resolve_near() {
+    assert_eq!(
+        resolve_near(date_time(13, 23, 50), time(0, 10)),
+        date_time(14, 0, 10)
+    );
+    assert_eq!(
+        resolve_near(date_time(14, 0, 10), time(23, 50)),
+        date_time(13, 23, 50)
+    );
+    assert_eq!(
+        resolve_near(date_time(13, 9, 0), time(8, 55)),
+        date_time(13, 8, 55)
+    );
+    assert_eq!(
+        resolve_near(date_time(13, 9, 0), time(21, 0)),
+        date_time(13, 21, 0)
+    );
+}
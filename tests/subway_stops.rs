@@ -0,0 +1,110 @@
+use septa_api::types::{
+    BroadStreetStop, MarketFrankfordStop, NhslStop, RegionalRailStop, TransitStop,
+};
+use strum::IntoEnumIterator;
+
+#[test]
+fn test_subway_stop_names() -> Result<(), strum::ParseError> {
+    assert_eq!(
+        "15th St Station - MFL".parse::<MarketFrankfordStop>()?,
+        MarketFrankfordStop::FifteenthStStation
+    );
+    assert_eq!(
+        BroadStreetStop::CityHallStation.to_string(),
+        "City Hall Station - BSL"
+    );
+    assert!("City Hall Station - MFL"
+        .parse::<MarketFrankfordStop>()
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_subway_stop_order() {
+    let market_frankford_stops = MarketFrankfordStop::stops();
+    assert_eq!(market_frankford_stops.len(), 28);
+    assert_eq!(
+        market_frankford_stops.first(),
+        Some(&MarketFrankfordStop::SixtyNinthStTransportationCenter)
+    );
+    assert_eq!(
+        market_frankford_stops.last(),
+        Some(&MarketFrankfordStop::FrankfordTransportationCenter)
+    );
+
+    let broad_street_stops = BroadStreetStop::stops();
+    assert_eq!(broad_street_stops.len(), 22);
+    assert_eq!(
+        broad_street_stops.first(),
+        Some(&BroadStreetStop::FernRockTransportationCenter)
+    );
+    assert_eq!(
+        broad_street_stops.last(),
+        Some(&BroadStreetStop::NrgStation)
+    );
+    assert!(!broad_street_stops.contains(&BroadStreetStop::ChinatownStation));
+
+    assert_eq!(
+        BroadStreetStop::ridge_spur_stops(),
+        vec![
+            BroadStreetStop::GirardStation,
+            BroadStreetStop::FairmountStation,
+            BroadStreetStop::ChinatownStation,
+            BroadStreetStop::EighthMarketStation
+        ]
+    );
+}
+
+#[test]
+fn test_subway_transfers() {
+    assert_eq!(
+        MarketFrankfordStop::FifteenthStStation.transfers(),
+        vec![
+            TransitStop::BroadStreet(BroadStreetStop::CityHallStation),
+            TransitStop::RegionalRail(RegionalRailStop::SuburbanStation)
+        ]
+    );
+    assert_eq!(
+        BroadStreetStop::CityHallStation.transfers(),
+        vec![
+            TransitStop::MarketFrankford(MarketFrankfordStop::FifteenthStStation),
+            TransitStop::RegionalRail(RegionalRailStop::SuburbanStation)
+        ]
+    );
+    assert_eq!(
+        MarketFrankfordStop::EleventhStStation.transfers(),
+        vec![TransitStop::RegionalRail(
+            RegionalRailStop::JeffersonStation
+        )]
+    );
+    assert_eq!(
+        TransitStop::RegionalRail(RegionalRailStop::JeffersonStation).transfers(),
+        vec![TransitStop::MarketFrankford(
+            MarketFrankfordStop::EleventhStStation
+        )]
+    );
+    assert_eq!(
+        MarketFrankfordStop::SixtyNinthStTransportationCenter.transfers(),
+        vec![TransitStop::Nhsl(
+            NhslStop::SixtyNinthStTransportationCenter
+        )]
+    );
+    assert!(MarketFrankfordStop::TiogaStation.transfers().is_empty());
+
+    // Every transfer can be made in both directions
+    let stops = MarketFrankfordStop::iter()
+        .map(TransitStop::MarketFrankford)
+        .chain(BroadStreetStop::iter().map(TransitStop::BroadStreet));
+
+    for stop in stops {
+        for transfer in stop.transfers() {
+            assert!(
+                transfer.transfers().contains(&stop),
+                "{} transfers to {} but not back",
+                stop,
+                transfer
+            );
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use mockito::{Mock, ServerGuard};
 use septa_api::{
-    types::{RegionalRailStop, RegionalRailsLine, ServiceType},
+    types::{RegionalRailStop, RegionalRailsLine, ServiceType, Track},
     Client,
 };
 
@@ -83,8 +83,8 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(trains[0].heading, Some(189.8775840187919));
     assert_eq!(trains[0].late, 0);
     assert_eq!(trains[0].source, RegionalRailStop::NorristownTransitCenter);
-    assert_eq!(trains[0].track, "");
-    assert_eq!(trains[0].track_change, "");
+    assert_eq!(trains[0].track, None);
+    assert_eq!(trains[0].track_change, None);
 
     assert_eq!(trains[1].lat, 40.200600166667);
     assert_eq!(trains[1].lon, -75.270441);
@@ -97,8 +97,8 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(trains[1].heading, Some(326.98421204774684));
     assert_eq!(trains[1].late, 0);
     assert_eq!(trains[1].source, RegionalRailStop::Newark);
-    assert_eq!(trains[1].track, "");
-    assert_eq!(trains[1].track_change, "");
+    assert_eq!(trains[1].track, None);
+    assert_eq!(trains[1].track_change, None);
 
     assert_eq!(trains[2].lat, 39.953094545);
     assert_eq!(trains[2].lon, -75.162311045);
@@ -111,8 +111,8 @@ async fn test_deserialize1_async() -> Result<(), septa_api::errors::Error> {
     assert_eq!(trains[2].heading, Some(101.50453615740082));
     assert_eq!(trains[2].late, 0);
     assert_eq!(trains[2].source, RegionalRailStop::Wawa);
-    assert_eq!(trains[2].track, "1A");
-    assert_eq!(trains[2].track_change, "");
+    assert_eq!(trains[2].track, Some("1A".parse::<Track>()?));
+    assert_eq!(trains[2].track_change, None);
 
     mock_server.assert_async().await;
 
@@ -146,8 +146,8 @@ async fn test_endpoint_rush_hour_async() -> Result<(), septa_api::errors::Error>
     assert_eq!(trains[0].heading, Some(279.74492662582446));
     assert_eq!(trains[0].late, 0);
     assert_eq!(trains[0].source, RegionalRailStop::SuburbanStation);
-    assert_eq!(trains[0].track, "");
-    assert_eq!(trains[0].track_change, "");
+    assert_eq!(trains[0].track, None);
+    assert_eq!(trains[0].track_change, None);
 
     mock_server.assert_async().await;
 
@@ -174,7 +174,7 @@ async fn test_api_down_test_async() -> Result<(), septa_api::errors::Error> {
 
     assert!(trains.is_err());
     match trains.unwrap_err() {
-        septa_api::errors::Error::ApiErrorResponse(e) => assert_eq!(e, "We apologize for the inconvenience, but we are experiencing difficulties at this time.  TrainView has been disabled."),
+        septa_api::errors::Error::ApiErrorResponse { message, .. } => assert_eq!(message, "We apologize for the inconvenience, but we are experiencing difficulties at this time.  TrainView has been disabled."),
         _ => unreachable!()
     }
 
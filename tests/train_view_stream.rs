@@ -0,0 +1,204 @@
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use mockito::{Mock, ServerGuard};
+use septa_api::{
+    stream::TrainViewEvent,
+    types::{RegionalRailStop, Track},
+    Client,
+};
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+const SNAPSHOT_1: &str = r#"
+[
+    {"lat":"39.954174265","lon":"-75.16763361","trainno":"2333","service":"LOCAL","dest":"Wawa","currentstop":"Suburban Station","nextstop":"30th Street Station","line":"Media\/Wawa","consist":"872,871,858,857","heading":"189.8775840187919","late":0,"SOURCE":"Norristown Transit Center","TRACK":"","TRACK_CHANGE":""},
+    {"lat":"40.200600166667","lon":"-75.270441","trainno":"2530","service":"LOCAL","dest":"Lansdale","currentstop":"Gwynedd Valley","nextstop":"North Wales","line":"Lansdale\/Doylestown","consist":"415,366,367,126,125","heading":"326.98421204774684","late":0,"SOURCE":"Newark","TRACK":"","TRACK_CHANGE":""}
+]"#;
+
+// Train 2333 moves to 30th St, picks up 2 mins of delay and a track change, 2530 is unchanged
+const SNAPSHOT_2: &str = r#"
+[
+    {"lat":"39.956670","lon":"-75.181660","trainno":"2333","service":"LOCAL","dest":"Wawa","currentstop":"30th Street Station","nextstop":"Penn Medicine Station","line":"Media\/Wawa","consist":"872,871,858,857","heading":"189.8775840187919","late":2,"SOURCE":"Norristown Transit Center","TRACK":"4","TRACK_CHANGE":"5"},
+    {"lat":"40.200600166667","lon":"-75.270441","trainno":"2530","service":"LOCAL","dest":"Lansdale","currentstop":"Gwynedd Valley","nextstop":"North Wales","line":"Lansdale\/Doylestown","consist":"415,366,367,126,125","heading":"326.98421204774684","late":0,"SOURCE":"Newark","TRACK":"","TRACK_CHANGE":""}
+]"#;
+
+// Train 2530 finishes its run and 3236 enters service
+const SNAPSHOT_3: &str = r#"
+[
+    {"lat":"39.956670","lon":"-75.181660","trainno":"2333","service":"LOCAL","dest":"Wawa","currentstop":"30th Street Station","nextstop":"Penn Medicine Station","line":"Media\/Wawa","consist":"872,871,858,857","heading":"189.8775840187919","late":2,"SOURCE":"Norristown Transit Center","TRACK":"4","TRACK_CHANGE":"5"},
+    {"lat":"39.953094545","lon":"-75.162311045","trainno":"3236","service":"LOCAL","dest":"Norristown Transit Center","currentstop":"Suburban Station","nextstop":"Jefferson Station","line":"Manayunk\/Norristown","consist":"705,716,861,862","heading":"101.50453615740082","late":0,"SOURCE":"Wawa","TRACK":"1A","TRACK_CHANGE":""}
+]"#;
+
+#[tokio::test]
+async fn test_train_view_stream_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mut mocks = Vec::new();
+    for snapshot in [SNAPSHOT_1, SNAPSHOT_2, SNAPSHOT_3] {
+        mocks.push(
+            create_mock_server(&mut server, "/TrainView/index.php")
+                .with_body(snapshot)
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+
+    let client = Client::with_base_url(server.url().as_str());
+    let events = client
+        .train_view_stream(Duration::from_millis(10))
+        .take(8)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // First snapshot
+    assert!(
+        matches!(&events[0], TrainViewEvent::TrainAppeared(train) if train.train_number == "2333")
+    );
+    assert!(
+        matches!(&events[1], TrainViewEvent::TrainAppeared(train) if train.train_number == "2530")
+    );
+
+    // Second snapshot
+    match &events[2] {
+        TrainViewEvent::PositionUpdated { previous, current } => {
+            assert_eq!(current.train_number, "2333");
+            assert_eq!((previous.lat, previous.lon), (39.954174265, -75.16763361));
+            assert_eq!((current.lat, current.lon), (39.956670, -75.181660));
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+    match &events[3] {
+        TrainViewEvent::LatenessChanged { previous, current } => {
+            assert_eq!(previous.late, 0);
+            assert_eq!(current.late, 2);
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+    match &events[4] {
+        TrainViewEvent::NextStopChanged { previous, current } => {
+            assert_eq!(previous.next_stop, RegionalRailStop::Gray30thStreet);
+            assert_eq!(current.next_stop, RegionalRailStop::PennMedicineStation);
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+    match &events[5] {
+        TrainViewEvent::TrackChanged { previous, current } => {
+            assert_eq!(previous.track, None);
+            assert_eq!(current.track, Some("4".parse::<Track>()?));
+            assert_eq!(current.track_change, Some("5".parse::<Track>()?));
+            assert!(!previous.track_assignment().is_changed());
+            assert!(current.track_assignment().is_changed());
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+
+    // Third snapshot
+    assert!(
+        matches!(&events[6], TrainViewEvent::TrainAppeared(train) if train.train_number == "3236")
+    );
+    assert!(
+        matches!(&events[7], TrainViewEvent::TrainDisappeared(train) if train.train_number == "2530")
+    );
+    assert_eq!(events[7].train_number(), "2530");
+
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_train_view_stream_error_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let first_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(SNAPSHOT_1)
+        .expect(1)
+        .create_async()
+        .await;
+    let failing_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(r#"[{"error": "TrainView has been disabled."}]"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let last_mock = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(SNAPSHOT_1)
+        .expect_at_least(1)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let mut events = Box::pin(client.train_view_stream(Duration::from_millis(10)));
+
+    assert!(matches!(
+        events.next().await,
+        Some(Ok(TrainViewEvent::TrainAppeared(_)))
+    ));
+    assert!(matches!(
+        events.next().await,
+        Some(Ok(TrainViewEvent::TrainAppeared(_)))
+    ));
+    assert!(matches!(
+        events.next().await,
+        Some(Err(septa_api::errors::Error::ApiErrorResponse { .. }))
+    ));
+
+    // The snapshot after the error is unchanged, so nothing is yielded until the stream is dropped
+    let next = tokio::time::timeout(Duration::from_millis(100), events.next()).await;
+    assert!(next.is_err());
+
+    first_mock.assert_async().await;
+    failing_mock.assert_async().await;
+    drop(events);
+    last_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[test]
+fn test_train_view_diff_unchanged() {
+    let trains: Vec<septa_api::responses::Train> =
+        serde_json::from_str(SNAPSHOT_1).expect("snapshot should deserialize");
+
+    assert!(TrainViewEvent::diff(&trains, &trains).is_empty());
+    assert_eq!(TrainViewEvent::diff(&[], &trains).len(), 2);
+    assert_eq!(TrainViewEvent::diff(&trains, &[]).len(), 2);
+}
+
+#[test]
+fn test_train_view_stream_outside_runtime() {
+    // Building the stream must neither need a runtime nor poll anything
+    let client = Client::with_base_url("http://localhost:1");
+    let events = client.train_view_stream(Duration::ZERO);
+    drop(events);
+}
+
+#[tokio::test]
+async fn test_train_view_stream_zero_interval_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TrainView/index.php")
+        .with_body(SNAPSHOT_1)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let events = client
+        .train_view_stream(Duration::ZERO)
+        .take(2)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(events.len(), 2);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
@@ -0,0 +1,348 @@
+use mockito::{Mock, ServerGuard};
+use septa_api::{
+    requests::TransitViewRequest,
+    types::{BusRoute, NhslStop},
+    Client,
+};
+
+fn create_mock_server(server: &mut ServerGuard, endpoint: &str) -> Mock {
+    server.mock("GET", endpoint)
+}
+
+#[tokio::test]
+async fn test_transit_view_deserialize1_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TransitView/index.php?route=17")
+        .with_body(
+            r#"
+        {
+            "bus": [
+                {
+                    "lat": "39.952492",
+                    "lng": "-75.162498",
+                    "label": "8614",
+                    "VehicleID": "8614",
+                    "BlockID": "8160",
+                    "Direction": "SouthBound",
+                    "destination": "20th-Johnston",
+                    "trip": "358327",
+                    "heading": 180,
+                    "Offset": "2",
+                    "Offset_sec": "125",
+                    "late": 4
+                },
+                {
+                    "lat": "39.930321",
+                    "lng": "-75.174851",
+                    "label": "3216",
+                    "VehicleID": "3216",
+                    "BlockID": "8162",
+                    "Direction": "NorthBound",
+                    "destination": null,
+                    "trip": null,
+                    "heading": null,
+                    "Offset": "0",
+                    "Offset_sec": "12",
+                    "late": 0
+                }
+            ]
+        }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .transit_view(TransitViewRequest {
+            route: "17".parse()?,
+        })
+        .await?;
+
+    assert_eq!(response.vehicles.len(), 2);
+    assert_eq!(response.vehicles[0].lat, 39.952492);
+    assert_eq!(response.vehicles[0].lon, -75.162498);
+    assert_eq!(response.vehicles[0].label, "8614");
+    assert_eq!(response.vehicles[0].vehicle_id, "8614");
+    assert_eq!(response.vehicles[0].block_id, "8160");
+    assert_eq!(response.vehicles[0].direction, "SouthBound");
+    assert_eq!(
+        response.vehicles[0].destination,
+        Some("20th-Johnston".to_string())
+    );
+    assert_eq!(response.vehicles[0].trip, Some("358327".to_string()));
+    assert_eq!(response.vehicles[0].heading, Some(180.0));
+    assert_eq!(response.vehicles[0].offset, 2);
+    assert_eq!(response.vehicles[0].late, 4);
+
+    assert_eq!(response.vehicles[1].vehicle_id, "3216");
+    assert_eq!(response.vehicles[1].direction, "NorthBound");
+    assert_eq!(response.vehicles[1].destination, None);
+    assert_eq!(response.vehicles[1].trip, None);
+    assert_eq!(response.vehicles[1].heading, None);
+    assert_eq!(response.vehicles[1].offset, 0);
+    assert_eq!(response.vehicles[1].late, 0);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transit_view_empty_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TransitView/index.php?route=G1")
+        .with_body(r#"{"bus":[]}"#)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .transit_view(TransitViewRequest {
+            route: "G1".parse()?,
+        })
+        .await?;
+
+    assert!(response.vehicles.is_empty());
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transit_view_nhsl_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TransitView/index.php?route=NHSL")
+        .with_body(
+            r#"
+        {
+            "bus": [
+                {
+                    "lat": "40.040562",
+                    "lng": "-75.357571",
+                    "label": "135",
+                    "VehicleID": "135",
+                    "BlockID": "501",
+                    "Direction": "NorthBound",
+                    "destination": "Norristown TC",
+                    "trip": "1045613",
+                    "heading": 315,
+                    "Offset": "0",
+                    "Offset_sec": "31",
+                    "late": 1,
+                    "next_stop_name": "Villanova Station - NHSL"
+                },
+                {
+                    "lat": "39.968541",
+                    "lng": "-75.272125",
+                    "label": "140",
+                    "VehicleID": "140",
+                    "BlockID": "507",
+                    "Direction": "SouthBound",
+                    "destination": "69th St TC",
+                    "trip": "1045688",
+                    "heading": 120,
+                    "Offset": "1",
+                    "Offset_sec": "64",
+                    "late": 0,
+                    "next_stop_name": "Parkview Station"
+                },
+                {
+                    "lat": "40.113166",
+                    "lng": "-75.344309",
+                    "label": "141",
+                    "VehicleID": "141",
+                    "BlockID": "509",
+                    "Direction": "NorthBound",
+                    "destination": "Norristown TC",
+                    "trip": "1045690",
+                    "heading": 0,
+                    "Offset": "0",
+                    "Offset_sec": "5",
+                    "late": 0,
+                    "next_stop_name": "Norristown Transportation Center - NHSL"
+                },
+                {
+                    "lat": "39.962230",
+                    "lng": "-75.258660",
+                    "label": "142",
+                    "VehicleID": "142",
+                    "BlockID": "510",
+                    "Direction": "NorthBound",
+                    "destination": "Norristown TC",
+                    "trip": "1045692",
+                    "heading": 0,
+                    "Offset": "0",
+                    "Offset_sec": "5",
+                    "late": 0,
+                    "next_stop_name": ""
+                }
+            ]
+        }"#,
+        )
+        .create_async()
+        .await;
+
+    let route = "NHSL".parse::<BusRoute>()?;
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .transit_view(TransitViewRequest {
+            route: route.clone(),
+        })
+        .await?;
+
+    assert_eq!(response.vehicles.len(), 4);
+
+    assert_eq!(
+        response.vehicles[0].nhsl_next_stop(&route),
+        Some(NhslStop::VillanovaStation)
+    );
+    assert_eq!(
+        response.vehicles[0].nhsl_current_stop(&route),
+        Some(NhslStop::StadiumStation)
+    );
+
+    // The " - NHSL" suffix is optional
+    assert_eq!(
+        response.vehicles[1].nhsl_next_stop(&route),
+        Some(NhslStop::ParkviewStation)
+    );
+    assert_eq!(
+        response.vehicles[1].nhsl_current_stop(&route),
+        Some(NhslStop::TownshipLineRdStation)
+    );
+
+    // A northbound vehicle heading to Norristown has passed every other stop
+    assert_eq!(
+        response.vehicles[2].nhsl_next_stop(&route),
+        Some(NhslStop::NorristownTransportationCenter)
+    );
+    assert_eq!(
+        response.vehicles[2].nhsl_current_stop(&route),
+        Some(NhslStop::BridgeportStation)
+    );
+
+    assert_eq!(response.vehicles[3].next_stop_name, None);
+    assert_eq!(response.vehicles[3].nhsl_next_stop(&route), None);
+    assert_eq!(response.vehicles[3].nhsl_current_stop(&route), None);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transit_view_all_deserialize1_async() -> Result<(), septa_api::errors::Error> {
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TransitViewAll/index.php")
+        .with_body(
+            r#"
+        {
+            "routes": [
+                {
+                    "10": [
+                        {
+                            "lat": "39.962364",
+                            "lng": "-75.225998",
+                            "label": "9068",
+                            "VehicleID": "9068",
+                            "BlockID": "9022",
+                            "Direction": "WestBound",
+                            "destination": "63rd-Malvern",
+                            "trip": "231450",
+                            "heading": "270.5",
+                            "Offset": "1",
+                            "Offset_sec": "70",
+                            "late": 2
+                        }
+                    ],
+                    "LUCYGO": []
+                }
+            ]
+        }"#,
+        )
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client.transit_view_all().await?;
+
+    assert_eq!(response.routes.len(), 2);
+    assert!(response.routes[&"LUCYGO".parse::<BusRoute>()?].is_empty());
+
+    let trolleys = &response.routes[&"10".parse::<BusRoute>()?];
+    assert_eq!(trolleys.len(), 1);
+    assert_eq!(trolleys[0].lat, 39.962364);
+    assert_eq!(trolleys[0].lon, -75.225998);
+    assert_eq!(trolleys[0].vehicle_id, "9068");
+    assert_eq!(trolleys[0].block_id, "9022");
+    assert_eq!(trolleys[0].destination, Some("63rd-Malvern".to_string()));
+    assert_eq!(trolleys[0].heading, Some(270.5));
+    assert_eq!(trolleys[0].offset, 1);
+    assert_eq!(trolleys[0].late, 2);
+    assert_eq!(trolleys[0].next_stop_name, None);
+
+    // Route 10 does not run on the NHSL
+    assert_eq!(trolleys[0].nhsl_next_stop(&"10".parse()?), None);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transit_view_bus_named_like_nhsl_stop_async() -> Result<(), septa_api::errors::Error>
+{
+    let mut server = mockito::Server::new_async().await;
+    let mock_server = create_mock_server(&mut server, "/TransitView/index.php?route=104")
+        .with_body(
+            r#"
+        {
+            "bus": [
+                {
+                    "lat": "39.962411",
+                    "lng": "-75.258915",
+                    "label": "3410",
+                    "VehicleID": "3410",
+                    "BlockID": "7015",
+                    "Direction": "SouthBound",
+                    "destination": "69th St TC",
+                    "trip": "1183520",
+                    "heading": 90,
+                    "Offset": "0",
+                    "Offset_sec": "18",
+                    "late": 3,
+                    "next_stop_name": "69th St Transportation Center"
+                }
+            ]
+        }"#,
+        )
+        .create_async()
+        .await;
+
+    let route = "104".parse::<BusRoute>()?;
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client
+        .transit_view(TransitViewRequest {
+            route: route.clone(),
+        })
+        .await?;
+
+    // The bus's next stop shares its name with the NHSL terminal, but the bus is not an NHSL
+    // vehicle
+    assert_eq!(response.vehicles.len(), 1);
+    assert_eq!(response.vehicles[0].nhsl_direction(&route), None);
+    assert_eq!(response.vehicles[0].nhsl_next_stop(&route), None);
+    assert_eq!(response.vehicles[0].nhsl_current_stop(&route), None);
+
+    // The same record returned for the NHSL is typed
+    let nhsl = "NHSL".parse::<BusRoute>()?;
+    assert_eq!(
+        response.vehicles[0].nhsl_next_stop(&nhsl),
+        Some(NhslStop::SixtyNinthStTransportationCenter)
+    );
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
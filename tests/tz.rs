@@ -0,0 +1,125 @@
+#![cfg(feature = "chrono-tz")]
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use septa_api::{
+    responses::RailSchedule,
+    tz::{to_septa_time, SEPTA_TIME_ZONE},
+};
+
+fn naive(year: i32, month: u32, day: u32, hour: u32, min: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(year, month, day)
+        .unwrap()
+        .and_hms_opt(hour, min, 0)
+        .unwrap()
+}
+
+#[test]
+fn test_standard_and_daylight_offsets() {
+    assert_eq!(
+        to_septa_time(naive(2023, 1, 15, 8, 30)).to_rfc3339(),
+        "2023-01-15T08:30:00-05:00"
+    );
+    assert_eq!(
+        to_septa_time(naive(2023, 7, 4, 17, 15)).to_rfc3339(),
+        "2023-07-04T17:15:00-04:00"
+    );
+    assert_eq!(
+        to_septa_time(naive(2023, 7, 4, 17, 15)).timezone(),
+        SEPTA_TIME_ZONE
+    );
+}
+
+#[test]
+fn test_spring_forward_transition() {
+    // The clocks jumped from 2:00 AM EST to 3:00 AM EDT on March 12th 2023
+    assert_eq!(
+        to_septa_time(naive(2023, 3, 12, 1, 59)).to_rfc3339(),
+        "2023-03-12T01:59:00-05:00"
+    );
+    assert_eq!(
+        to_septa_time(naive(2023, 3, 12, 2, 0)).to_rfc3339(),
+        "2023-03-12T03:00:00-04:00"
+    );
+    assert_eq!(
+        to_septa_time(naive(2023, 3, 12, 2, 30)).to_rfc3339(),
+        "2023-03-12T03:30:00-04:00"
+    );
+    assert_eq!(
+        to_septa_time(naive(2023, 3, 12, 3, 0)).to_rfc3339(),
+        "2023-03-12T03:00:00-04:00"
+    );
+
+    // Only an hour passes between 1:30 AM and 3:30 AM
+    assert_eq!(
+        (to_septa_time(naive(2023, 3, 12, 3, 30)) - to_septa_time(naive(2023, 3, 12, 1, 30)))
+            .num_minutes(),
+        60
+    );
+}
+
+#[test]
+fn test_fall_back_transition() {
+    // The clocks fell back from 2:00 AM EDT to 1:00 AM EST on November 5th 2023, so every time
+    // between 1:00 AM and 2:00 AM happened twice
+    assert_eq!(
+        to_septa_time(naive(2023, 11, 5, 0, 59)).to_rfc3339(),
+        "2023-11-05T00:59:00-04:00"
+    );
+    assert_eq!(
+        to_septa_time(naive(2023, 11, 5, 1, 0)).to_rfc3339(),
+        "2023-11-05T01:00:00-04:00"
+    );
+    assert_eq!(
+        to_septa_time(naive(2023, 11, 5, 1, 30)).to_rfc3339(),
+        "2023-11-05T01:30:00-04:00"
+    );
+    assert_eq!(
+        to_septa_time(naive(2023, 11, 5, 2, 0)).to_rfc3339(),
+        "2023-11-05T02:00:00-05:00"
+    );
+
+    // Two hours pass between 1:00 AM and 2:00 AM
+    assert_eq!(
+        (to_septa_time(naive(2023, 11, 5, 2, 0)) - to_septa_time(naive(2023, 11, 5, 1, 0)))
+            .num_minutes(),
+        120
+    );
+}
+
+#[test]
+fn test_rail_schedule_local_times() -> Result<(), serde_json::Error> {
+    let schedule: RailSchedule = serde_json::from_str(
+        r#"
+        {
+            "station": "Suburban Station",
+            "sched_tm": "1:30 am",
+            "est_tm": "2:35 am",
+            "act_tm": "na"
+        }"#,
+    )?;
+
+    // 1:30 AM and 2:35 AM are after midnight, so they are on the day after the service date
+    let fall_back = NaiveDate::from_ymd_opt(2023, 11, 4).unwrap();
+    assert_eq!(
+        schedule.scheduled_time_local(fall_back).to_rfc3339(),
+        "2023-11-05T01:30:00-04:00"
+    );
+    assert_eq!(
+        schedule.estimated_time_local(fall_back).to_rfc3339(),
+        "2023-11-05T02:35:00-05:00"
+    );
+    assert_eq!(schedule.actual_time_local(fall_back), None);
+
+    let spring_forward = NaiveDate::from_ymd_opt(2023, 3, 11).unwrap();
+    assert_eq!(
+        schedule.estimated_time_local(spring_forward).to_rfc3339(),
+        "2023-03-12T03:35:00-04:00"
+    );
+
+    assert_eq!(
+        schedule.scheduled_time,
+        NaiveTime::from_hms_opt(1, 30, 0).unwrap()
+    );
+
+    Ok(())
+}